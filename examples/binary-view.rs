@@ -0,0 +1,27 @@
+use web_reference::prelude::*;
+
+fn main() -> Result<(), BinaryError> {
+    let specs = WebReference::embedded();
+    let binary = specs.to_binary()?;
+    let view = ReferenceView::new(&binary)?;
+
+    println!("{} bytes, {} tags", binary.len(), view.tag_names().count());
+
+    // borrowed from the binary, nothing is deserialized
+    let div = view.tag("div").expect("expect div to be defined");
+
+    println!("{} accepts global attributes: {}", div.name, div.global_attributes);
+
+    describe(specs);
+    describe(&view);
+
+    Ok(())
+}
+
+fn describe<S: ReferenceStore>(store: &S) where S::Error: std::fmt::Debug {
+    let div = store.get_tag("div").expect("expect lookup to succeed").expect("expect div to be defined");
+    let click = store.get_event("onclick").expect("expect lookup to succeed").expect("expect onclick to be defined");
+
+    println!("{}: {}", div.name, div.description);
+    println!("{}: {}", click.name, click.description);
+}
@@ -0,0 +1,16 @@
+use std::env;
+use std::io;
+
+use web_reference::prelude::*;
+
+// compares a previous html-5-specs.json, the first argument, with the embedded reference
+fn main() -> io::Result<()> {
+    let previous = env::args().nth(1).expect("expect the path of a previous html-5-specs.json");
+    let previous = WebReference::load_from_path(previous)?;
+
+    let changelog = previous.diff(WebReference::embedded());
+
+    println!("{}", changelog.to_markdown());
+
+    Ok(())
+}
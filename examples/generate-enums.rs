@@ -0,0 +1,8 @@
+use web_reference::prelude::WebReference;
+
+// in a build.rs, write into `Path::new(&env::var("OUT_DIR")?).join("html.rs")` and `include!` it
+fn main() {
+    let specs = WebReference::embedded();
+
+    print!("{}", specs.generate_rust());
+}
@@ -0,0 +1,8 @@
+use web_reference::prelude::WebReference;
+
+// redirect into a `.d.ts` file, i.e. `cargo run --example generate-typescript > html.d.ts`
+fn main() {
+    let specs = WebReference::embedded();
+
+    print!("{}", specs.generate_typescript());
+}
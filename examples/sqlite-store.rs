@@ -0,0 +1,19 @@
+use web_reference::prelude::*;
+
+fn main() -> rusqlite::Result<()> {
+    let specs = WebReference::embedded();
+    let store = SqliteStore::in_memory(specs)?;
+
+    describe(specs);
+    describe(&store);
+
+    Ok(())
+}
+
+fn describe<S: ReferenceStore>(store: &S) where S::Error: std::fmt::Debug {
+    let div = store.get_tag("div").expect("expect lookup to succeed").expect("expect div to be defined");
+    let attributes = store.get_tag_attributes("a").expect("expect lookup to succeed");
+
+    println!("{}: {}", div.name, div.description);
+    println!("a: {}", attributes.iter().map(|attribute| attribute.name.as_str()).collect::<Vec<_>>().join(", "));
+}
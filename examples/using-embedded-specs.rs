@@ -0,0 +1,10 @@
+use web_reference::prelude::WebReference;
+
+fn main() {
+    let specs = WebReference::embedded();
+
+    let div_tag = specs.get_tag("div").expect("expect div to be defined");
+    let _div_attributes = specs.get_tag_attributes(div_tag);
+
+    println!("{div_tag:#?}");
+}
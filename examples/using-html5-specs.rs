@@ -3,7 +3,7 @@ use std::io;
 use web_reference::prelude::WebReference;
 
 fn main() -> io::Result<()> {
-    let specs = WebReference::load_json()?;
+    let specs = WebReference::load_specs()?;
 
     println!("{specs:#?}");
 
@@ -0,0 +1,22 @@
+use web_reference::prelude::WebReference;
+
+const DOCUMENT: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<body>
+  <center>obsolete</center>
+  <a herf="index.html" onclik="go()">home</a>
+  <img src="logo.png" width="wide" checked>
+  <a href="help.html" target="_blnk">help</a>
+  <input type="text" autocomplete="maybe" required>
+  <blink>unknown</blink>
+</body>
+</html>
+"#;
+
+fn main() {
+    let specs = WebReference::embedded();
+
+    for diagnostic in specs.validate_document(DOCUMENT) {
+        println!("{diagnostic}");
+    }
+}
@@ -0,0 +1,92 @@
+use clap::{Parser, Subcommand, ValueEnum};
+
+use web_reference::prelude::BrowserTargets;
+
+/// Query the reference of HTML 5 specifications
+#[derive(Debug, Parser)]
+#[command(name = "web-reference", version, about)]
+pub struct Cli {
+    /// Output json instead of human readable text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Details of a tag, i.e. `tag div` or `tag --svg path`
+    Tag {
+        name: String,
+
+        /// An svg tag rather than an html tag
+        #[arg(long)]
+        svg: bool,
+    },
+
+    /// Details of an attribute, of every tag it belongs to unless a tag is given
+    Attr {
+        name: String,
+
+        /// Only the attribute as it applies to this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
+
+    /// Details of an event, with or without the `on` prefix
+    Event {
+        name: String,
+    },
+
+    /// Details of a css property, i.e. `css text-align`
+    Css {
+        name: String,
+    },
+
+    /// Details of an aria role, state or property, i.e. `aria checkbox` or `aria aria-checked`
+    Aria {
+        name: String,
+    },
+
+    /// Language and region of a language tag, i.e. `lang en-GB`
+    Lang {
+        tag: String,
+    },
+
+    /// Tags, attributes or events of a category, i.e. `category tags forms`
+    Category {
+        #[arg(value_enum)]
+        kind: CategoryKind,
+
+        name: String,
+    },
+
+    /// Ranked search of names and descriptions
+    Search {
+        #[arg(required = true)]
+        text: Vec<String>,
+
+        /// Maximum number of hits
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Tags, attributes and events not supported by the browser targets
+    Compat {
+        /// Minimum browser versions, i.e. "chrome >= 60, safari >= 12"
+        #[arg(long)]
+        target: BrowserTargets,
+
+        /// Only the attributes of this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub enum CategoryKind {
+    Tags,
+    Attrs,
+    Events,
+}
@@ -0,0 +1,363 @@
+use std::io::Write;
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::json;
+
+use web_reference::prelude::*;
+
+use crate::cli::{CategoryKind, Cli, Command};
+use crate::output::{print_description, print_json, print_list, print_supported, print_values};
+
+pub fn run(reference: &WebReference, cli: &Cli, out: &mut impl Write) -> Result<()> {
+    let json = cli.json;
+
+    match &cli.command {
+        Command::Tag { name, svg } =>
+            tag(out, reference, name, *svg, json),
+        Command::Attr { name, tag } =>
+            attribute(out, reference, name, tag.as_deref(), json),
+        Command::Event { name } =>
+            event(out, reference, name, json),
+        Command::Css { name } =>
+            css(out, reference, name, json),
+        Command::Aria { name } =>
+            aria(out, reference, name, json),
+        Command::Lang { tag } =>
+            language(out, reference, tag, json),
+        Command::Category { kind, name } =>
+            category(out, reference, *kind, name, json),
+        Command::Search { text, limit } =>
+            search(out, reference, &text.join(" "), *limit, json),
+        Command::Compat { target, tag } =>
+            compat(out, reference, target, tag.as_deref(), json),
+    }
+}
+
+fn aria(out: &mut impl Write, reference: &WebReference, name: &str, json: bool) -> Result<()> {
+    let name = name.to_ascii_lowercase();
+
+    if let Some(attribute) = reference.get_aria_attribute(&name) {
+        if json {
+            return print_json(out, attribute);
+        }
+
+        writeln!(out, "{}", attribute.name)?;
+        print_description(out, &attribute.description)?;
+        writeln!(out, "  {}, value: {}, global: {}, deprecated: {}", attribute.kind, attribute.value_type, attribute.global, attribute.deprecated)?;
+        print_list(out, "tokens", &attribute.tokens)?;
+
+        return Ok(());
+    }
+
+    let Some(role) = reference.get_aria_role(&name) else {
+        bail!("unknown aria role, state or property {name:?}");
+    };
+
+    if json {
+        return print_json(out, role);
+    }
+
+    writeln!(out, "{}{}", role.name, if role.is_abstract { " (abstract)" } else { "" })?;
+    print_description(out, &role.description)?;
+    print_list(out, "superclasses", &role.superclasses)?;
+    print_list(out, "required", &role.required_attributes)?;
+    print_list(out, "supported", &role.supported_attributes)?;
+
+    Ok(())
+}
+
+fn attribute(out: &mut impl Write, reference: &WebReference, name: &str, tag: Option<&str>, json: bool) -> Result<()> {
+    let name = name.to_ascii_lowercase();
+
+    let attributes = if let Some(tag) = tag {
+        let tag = find_tag(reference, tag)?;
+
+        let Some(attribute) = reference.find_tag_attribute(&name, tag) else {
+            let suggestions = reference.suggest_attribute(&tag.name, &name).into_iter().map(|name| format!("{name:?}"));
+
+            bail!("attribute {name:?} is not valid on <{}>{}", tag.name, did_you_mean(suggestions));
+        };
+
+        if json {
+            return print_json(out, attribute);
+        }
+
+        vec![(tag.name.as_str(), attribute)]
+    } else {
+        let Some(attributes) = reference.get_attributes(&name) else {
+            let suggestions = reference.suggest_any_attribute(&name).into_iter().map(|name| format!("{name:?}"));
+
+            bail!("unknown attribute {name:?}{}", did_you_mean(suggestions));
+        };
+
+        if json {
+            return print_json(out, attributes);
+        }
+
+        let mut attributes = attributes.iter().map(|(tag, attribute)| (tag.as_str(), attribute)).collect::<Vec<_>>();
+
+        attributes.sort_unstable_by_key(|(tag, _)| *tag);
+
+        attributes
+    };
+
+    for (tag, attribute) in attributes {
+        writeln!(out, "{} ({tag})", attribute.name)?;
+        print_description(out, &attribute.description)?;
+        print_values(out, &attribute.values)?;
+        print_supported(out, &attribute.supported)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn category(out: &mut impl Write, reference: &WebReference, kind: CategoryKind, name: &str, json: bool) -> Result<()> {
+    let name = name.to_ascii_lowercase();
+
+    let entries = match kind {
+        CategoryKind::Tags => {
+            let category = TagCategory::try_from(name.as_str()).map_err(anyhow::Error::msg)?;
+            let interned = WebReference::embedded_interned();
+            let tags = interned.get_tags_of_category(category).iter().map(|&tag| interned.tag(tag)).collect::<Vec<_>>();
+
+            if json {
+                return print_json(out, &tags);
+            }
+
+            tags.into_iter().map(|tag| (format!("<{}>", tag.name), &tag.description)).collect::<Vec<_>>()
+        }
+        CategoryKind::Attrs => {
+            let category = AttributeCategory::try_from(name.as_str()).map_err(anyhow::Error::msg)?;
+            let attributes = reference.get_attributes_of_category(category).unwrap_or_default();
+
+            if json {
+                return print_json(out, &attributes);
+            }
+
+            attributes.into_iter().map(|attribute| (attribute.name.clone(), &attribute.description)).collect()
+        }
+        CategoryKind::Events => {
+            let category = EventCategory::try_from(name.as_str()).map_err(anyhow::Error::msg)?;
+            let interned = WebReference::embedded_interned();
+            let events = interned.get_events_of_category(category).iter().map(|&event| interned.event(event)).collect::<Vec<_>>();
+
+            if json {
+                return print_json(out, &events);
+            }
+
+            events.into_iter().map(|event| (event.name.clone(), &event.description)).collect()
+        }
+    };
+
+    let mut entries = entries;
+
+    entries.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+    for (name, description) in entries {
+        writeln!(out, "{name:<24} {description}")?;
+    }
+
+    Ok(())
+}
+
+fn compat(out: &mut impl Write, reference: &WebReference, targets: &BrowserTargets, tag: Option<&str>, json: bool) -> Result<()> {
+    if let Some(tag) = tag {
+        let tag = find_tag(reference, tag)?;
+        let mut attributes = reference.unsupported_attributes_of(tag, targets).into_iter()
+            .map(|attribute| &attribute.name)
+            .collect::<Vec<_>>();
+
+        attributes.sort_unstable();
+
+        if json {
+            return print_json(out, &json!({ "tag": tag.name, "supported": reference.is_tag_supported(tag, targets), "attributes": attributes }));
+        }
+
+        if !reference.is_tag_supported(tag, targets) {
+            writeln!(out, "<{}> is not supported", tag.name)?;
+        }
+
+        print_list(out, "unsupported attributes", attributes)?;
+
+        return Ok(());
+    }
+
+    let tags = reference.unsupported_tags(targets).into_iter().map(|tag| &tag.name);
+    let events = reference.unsupported_events(targets).into_iter().map(|event| &event.name);
+
+    if json {
+        let mut tags = tags.collect::<Vec<_>>();
+        let mut events = events.collect::<Vec<_>>();
+
+        tags.sort_unstable();
+        events.sort_unstable();
+
+        return print_json(out, &json!({ "tags": tags, "events": events }));
+    }
+
+    print_list(out, "unsupported tags", tags)?;
+    print_list(out, "unsupported events", events)?;
+
+    Ok(())
+}
+
+fn css(out: &mut impl Write, reference: &WebReference, name: &str, json: bool) -> Result<()> {
+    let name = name.to_ascii_lowercase();
+
+    let Some(property) = reference.get_css_property(&name) else {
+        bail!("unknown css property {name:?}");
+    };
+
+    if json {
+        return print_json(out, property);
+    }
+
+    writeln!(out, "{}", property.name)?;
+    print_description(out, &property.description)?;
+    writeln!(out, "  syntax: {}", property.syntax)?;
+    writeln!(out, "  initial: {}, inherited: {}", property.initial, property.inherited)?;
+    print_list(out, "replaces attributes", &property.presentational_attributes)?;
+    print_supported(out, &property.supported)?;
+
+    Ok(())
+}
+
+fn event(out: &mut impl Write, reference: &WebReference, name: &str, json: bool) -> Result<()> {
+    let name = name.to_ascii_lowercase();
+    let name = if name.starts_with("on") { name } else { format!("on{name}") };
+
+    let Some(event) = reference.get_event(&name) else {
+        let suggestions = reference.suggest_event(&name).into_iter().map(|name| format!("{name:?}"));
+
+        bail!("unknown event {name:?}{}", did_you_mean(suggestions));
+    };
+
+    if json {
+        return print_json(out, event);
+    }
+
+    writeln!(out, "{}", event.name)?;
+    print_description(out, &event.description)?;
+    writeln!(out, "  bubbles: {}, cancelable: {}", event.details.bubbles, event.details.cancelable)?;
+    print_list(out, "event objects", &event.event_objects)?;
+
+    if let Some(fields) = reference.get_event_fields(event) {
+        print_list(out, "fields", fields.iter().map(|field| &field.name))?;
+    }
+
+    print_list(out, "deprecated", &event.deprecated)?;
+    print_supported(out, &event.supported)?;
+
+    Ok(())
+}
+
+fn find_tag<'a>(reference: &'a WebReference, name: &str) -> Result<&'a Tag> {
+    let name = name.trim_start_matches('<').trim_end_matches('>').to_ascii_lowercase();
+
+    let Some(tag) = reference.get_tag(&name) else {
+        let suggestions = reference.suggest_tag(&name).into_iter().map(|name| format!("<{name}>"));
+
+        bail!("unknown tag <{name}>{}", did_you_mean(suggestions));
+    };
+
+    Ok(tag)
+}
+
+fn language(out: &mut impl Write, reference: &WebReference, tag: &str, json: bool) -> Result<()> {
+    let Some(language) = reference.get_language(tag) else {
+        bail!("unknown language {tag:?}");
+    };
+
+    let country = reference.get_language_country(tag);
+
+    if json {
+        return print_json(out, &json!({ "language": language, "country": country }));
+    }
+
+    writeln!(out, "{tag}")?;
+    writeln!(out, "  language: {}", language.name)?;
+
+    if let Some(country) = country {
+        writeln!(out, "  region: {}", country.name)?;
+    }
+
+    Ok(())
+}
+
+fn search(out: &mut impl Write, reference: &WebReference, text: &str, limit: usize, json: bool) -> Result<()> {
+    let hits = reference.search(text).into_iter().take(limit).collect::<Vec<_>>();
+
+    if json {
+        return print_json(out, &hits);
+    }
+
+    for hit in hits {
+        let scope = match (&hit.tag, &hit.attribute) {
+            (Some(tag), Some(attribute)) => format!(" ({tag} {attribute})"),
+            (Some(tag), None) => format!(" ({tag})"),
+            _ => String::new(),
+        };
+
+        writeln!(out, "{:<10} {}{scope}", format!("{:?}", hit.kind).to_ascii_lowercase(), hit.name)?;
+        writeln!(out, "           {}", hit.snippet)?;
+    }
+
+    Ok(())
+}
+
+fn tag(out: &mut impl Write, reference: &WebReference, name: &str, svg: bool, json: bool) -> Result<()> {
+    let tag = if svg {
+        let name = name.trim_start_matches('<').trim_end_matches('>');
+
+        reference.get_namespaced_tag(Namespace::Svg, name)
+            .ok_or_else(|| anyhow!("unknown svg tag <{name}>"))?
+    } else {
+        find_tag(reference, name)?
+    };
+
+    if json {
+        return print_json(out, tag);
+    }
+
+    writeln!(out, "<{}>", tag.name)?;
+    print_description(out, &tag.description)?;
+
+    if let Some(suggestion) = tag.alternatives.suggestion() {
+        writeln!(out, "  obsolete, {suggestion}")?;
+    }
+
+    print_list(out, "attributes", &tag.attributes)?;
+    print_list(out, "optional attributes", &tag.optional_attributes)?;
+    writeln!(out, "  global attributes: {}, global events: {}", tag.global_attributes, tag.global_events)?;
+
+    if let Some(role) = &tag.aria.implicit_role {
+        writeln!(out, "  implicit role: {role}")?;
+    }
+
+    match &tag.aria.allowed_roles {
+        AllowedAriaRoles::Any => {}
+        AllowedAriaRoles::None => writeln!(out, "  allowed roles: none")?,
+        AllowedAriaRoles::Roles(roles) => print_list(out, "allowed roles", roles)?,
+    }
+
+    if let Some(model) = &tag.content_model {
+        print_list(out, "permitted parents", &model.parents)?;
+    }
+
+    if tag.syntax.kind != ElementKind::Normal {
+        writeln!(out, "  element kind: {}", tag.syntax.kind)?;
+    }
+
+    if let Some(rule) = &tag.syntax.start_tag_omission {
+        writeln!(out, "  start tag may be omitted: {}", rule.description)?;
+    }
+
+    if let Some(rule) = &tag.syntax.end_tag_omission {
+        writeln!(out, "  end tag may be omitted: {}", rule.description)?;
+    }
+
+    print_supported(out, &tag.supported)?;
+
+    Ok(())
+}
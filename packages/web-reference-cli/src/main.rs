@@ -0,0 +1,41 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+#![deny(clippy::cargo)]
+#![deny(missing_docs)]
+// ==============================================================
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::items_after_statements)]
+// syn 1 is still pulled in transitively by lazy-regex
+#![allow(clippy::multiple_crate_versions)]
+// ==============================================================
+
+#![doc = include_str ! ("../readme.md")]
+
+use std::io;
+use std::io::{BufWriter, ErrorKind, Write};
+
+use anyhow::Result;
+use clap::Parser;
+
+use web_reference::prelude::WebReference;
+
+use crate::cli::Cli;
+use crate::commands::run;
+
+mod cli;
+mod commands;
+mod output;
+
+fn main() -> Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    let result = run(WebReference::embedded(), &Cli::parse(), &mut out)
+        .and_then(|()| Ok(out.flush()?));
+
+    // a closed pipe, i.e. `| head`, ends the output early, it is not an error
+    match result {
+        Err(err) if err.downcast_ref::<io::Error>().is_some_and(|err| err.kind() == ErrorKind::BrokenPipe) => Ok(()),
+        result => result,
+    }
+}
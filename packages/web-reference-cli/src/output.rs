@@ -0,0 +1,78 @@
+use std::io::Write;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use web_reference::prelude::*;
+
+pub fn print_json(out: &mut impl Write, value: &impl Serialize) -> Result<()> {
+    writeln!(out, "{}", serde_json::to_string_pretty(value)?)?;
+
+    Ok(())
+}
+
+pub fn print_description(out: &mut impl Write, description: &Description) -> Result<()> {
+    if !description.is_empty() {
+        writeln!(out, "  {description}")?;
+    }
+
+    Ok(())
+}
+
+pub fn print_list<'a>(out: &mut impl Write, label: &str, names: impl IntoIterator<Item=&'a String>) -> Result<()> {
+    let mut names = names.into_iter().map(String::as_str).collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return Ok(());
+    }
+
+    names.sort_unstable();
+
+    writeln!(out, "  {label}: {}", names.join(", "))?;
+
+    Ok(())
+}
+
+pub fn print_supported(out: &mut impl Write, supported: &SupportedBrowsers) -> Result<()> {
+    if !supported.supported.is_empty() {
+        let mut browsers = supported.supported.iter().collect::<Vec<_>>();
+
+        browsers.sort_unstable_by_key(|(browser, _)| **browser);
+
+        let browsers = browsers.into_iter()
+            .map(|(browser, support)| format!("{browser} {support}"))
+            .collect::<Vec<_>>();
+
+        writeln!(out, "  supported: {}", browsers.join(", "))?;
+    }
+
+    for caveat in &supported.caveats {
+        writeln!(out, "  * {caveat}")?;
+    }
+
+    Ok(())
+}
+
+pub fn print_values(out: &mut impl Write, values: &AttributeValue) -> Result<()> {
+    match values {
+        AttributeValue::None => {}
+        AttributeValue::Values(values) => {
+            writeln!(out, "  values:")?;
+
+            let mut values = values.values().collect::<Vec<_>>();
+
+            values.sort_unstable_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+
+            for value in values {
+                writeln!(out, "    {:<16} {}", value.name, value.description)?;
+            }
+        }
+        typed => {
+            let description = typed.description().map(ToString::to_string).unwrap_or_default();
+
+            writeln!(out, "  value: {} {description}", typed.kind())?;
+        }
+    }
+
+    Ok(())
+}
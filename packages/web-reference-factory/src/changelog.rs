@@ -0,0 +1,59 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use anyhow::Result;
+
+use web_reference::prelude::*;
+
+pub const OUTPUT_FILE: &str = "html-5-specs.json";
+
+// the compact binary layout of the reference, next to its json
+#[cfg(feature = "binary")]
+const BINARY_FILE: &str = "html-5-specs.bin";
+
+// the changes of the last build, the markdown rendering is printed
+const CHANGELOG_FILE: &str = "html-5-specs.changelog.json";
+
+// the reference of the previous build, if any, to complement and compare the new one with, migrated if an
+// older build wrote it
+pub fn load_previous_reference() -> Result<Option<WebReference>> {
+    let path = Path::new(OUTPUT_FILE);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+
+    serde_json::from_reader(reader)
+        .map_err(anyhow::Error::from)
+        .and_then(|specs| WebReference::load_from_value(specs).map_err(anyhow::Error::from))
+        .map(Some)
+        .map_err(|err| anyhow!("Exception parsing previous reference {}\n  Err: {err}", path.display()))
+}
+
+pub fn write_reference(reference: &WebReference, previous: Option<&WebReference>) -> Result<()> {
+    let output = File::create(OUTPUT_FILE)?;
+    let writer = BufWriter::new(output);
+
+    serde_json::to_writer_pretty(writer, reference)?;
+
+    #[cfg(feature = "binary")]
+    std::fs::write(BINARY_FILE, reference.to_binary()?)?;
+
+    let Some(previous) = previous else {
+        return Ok(());
+    };
+
+    let changelog = previous.diff(reference);
+
+    println!("{}", changelog.to_markdown());
+
+    let output = File::create(CHANGELOG_FILE)?;
+    let writer = BufWriter::new(output);
+
+    serde_json::to_writer_pretty(writer, &changelog)?;
+
+    Ok(())
+}
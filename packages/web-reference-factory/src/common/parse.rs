@@ -80,12 +80,28 @@ pub fn parse_browser_support_details(header: &Node, err_details: &impl Fn() -> S
 
     next()?;
 
-    let (_label, support) = parse_browser_support(next()?)?;
+    // some tables repeat the browser names as a second header row
+    let mut row = next()?;
+
+    while is_browser_header(&row) {
+        row = next()?;
+    }
+
+    let (_label, support) = parse_browser_support(row)?;
     let caveats = parse_caveats(&table).into_iter().map(Description::from).collect();
 
     Ok(SupportedBrowsers { supported: support, caveats })
 }
 
+// a row of browser names instead of browser support versions
+fn is_browser_header(row: &Node) -> bool {
+    const BROWSERS: [&str; 6] = ["Chrome", "IE", "Edge", "Firefox", "Safari", "Opera"];
+
+    row.find(predicate::Name("td"))
+        .skip(1)
+        .any(|column| BROWSERS.contains(&column.text().trim()))
+}
+
 // parse an anchor's test as a label and href as an url link
 pub fn parse_label_and_url(node: &Option<Node>, urk_option: UrlOption, base: &str) -> Result<(String, Option<Url>)> {
     node.map_or_else(
@@ -146,7 +162,8 @@ fn parse_tag_alternative(source: &[&str]) -> Result<TagAlternatives> {
 
         Ok(match suggestion {
             "" => TagAlternatives::None,
-            "CSS" => TagAlternatives::Css,
+            // which properties is up to the factory, see `lookup::css_alternatives`
+            "CSS" => TagAlternatives::Css(AlternativeCssProperties::default()),
             tags => TagAlternatives::Tags(
                 tags.split(" or ").map(|v|
                     v.trim()
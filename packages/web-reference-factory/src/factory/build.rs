@@ -3,6 +3,7 @@ use itertools::Itertools;
 
 use web_reference::prelude::*;
 
+use crate::lookup;
 use crate::types::*;
 
 pub fn build_attributes(
@@ -95,7 +96,7 @@ pub fn build_events(events: RawEvents, events_attrs: RawAttributes) -> Result<Ev
                 None => EventBelongsTo::NotDefined
             };
 
-            let event_objects = objects.into_iter().map(|(obj, _url)| obj).collect();
+            let event_objects = objects.into_iter().map(|(obj, _url)| event_object_name(&obj)).collect();
 
             let event = Event {
                 name: event,
@@ -190,7 +191,13 @@ pub fn build_tags(tags: &RawTagsByCategory, details: &RawTagsDetails) -> Result<
                 optional_attributes,
                 global_attributes,
                 global_events,
-                alternatives: alts.clone(),
+                alternatives: match alts {
+                    TagAlternatives::Css(_) => TagAlternatives::Css(lookup::css_alternatives(name)),
+                    alts => alts.clone(),
+                },
+                aria: lookup::tag_aria(name),
+                content_model: lookup::content_model(name),
+                syntax: lookup::tag_syntax(name),
             };
 
             Ok((name.clone(), tag))
@@ -330,7 +337,70 @@ fn parse_tag_category(src: &str) -> Result<TagCategory> {
     })
 }
 
-#[allow(clippy::unnecessary_wraps)] // future impl
-pub fn event_objects(_objects: &[String]) -> Result<EventObjects> {
-    Ok(EventObjects::default())
+// the pages do not state types, they are left unknown, the reffy mode takes them from the IDL
+pub fn event_objects(objects: &RawEventObjectPages) -> EventObjects {
+    objects.iter()
+        .map(|(object, _url, members)| {
+            let mut fields = Fields::default();
+            let mut methods = Methods::default();
+
+            for (member, desc, parameters) in members {
+                let description = Description::from(desc.as_str());
+
+                // i.e. "getModifierState()"
+                if let Some((method, _parameters)) = member.split_once('(') {
+                    let parameter = parameters.iter()
+                        .map(|(name, desc)| {
+                            let parameter = Parameter {
+                                name: name.clone(),
+                                description: Description::from(desc.as_str()),
+                                r#type: Type::Unknown,
+                                default: String::new(),
+                            };
+
+                            (parameter.name.clone(), parameter)
+                        })
+                        .collect();
+
+                    let method = Method {
+                        name: method.to_string(),
+                        description,
+                        parameter,
+                    };
+
+                    methods.insert(method.name.clone(), method);
+                } else {
+                    let field = Field {
+                        name: member.clone(),
+                        description,
+                        r#type: Type::Unknown,
+                        default: String::new(),
+                    };
+
+                    fields.insert(field.name.clone(), field);
+                }
+            }
+
+            fields.sort_keys();
+            methods.sort_keys();
+
+            let object = Object {
+                name: event_object_name(object),
+                description: Description::default(),
+                fields,
+                methods,
+            };
+
+            (object.name.clone(), object)
+        }).collect::<EventObjects>()
+}
+
+// the pages spell `UIEvent` as "UiEvent", objects are named as in the IDL
+fn event_object_name(name: &str) -> String {
+    const RENAMED: [(&str, &str); 1] = [("UiEvent", "UIEvent")];
+
+    RENAMED.iter()
+        .find(|(page, _)| *page == name)
+        .map_or(name, |(_, idl)| idl)
+        .to_string()
 }
\ No newline at end of file
@@ -1,16 +1,17 @@
-use std::fs::File;
-use std::io::BufWriter;
-
 use anyhow::Result;
 
 use web_reference::prelude::*;
 
+use crate::changelog::{load_previous_reference, write_reference};
 use crate::common::*;
+use crate::lookup;
 
 mod build;
 mod scrape;
 
 pub fn run_factory() -> Result<()> {
+    let previous = load_previous_reference()?;
+
     let (tags, tags_categorized, tag_details) = {
         let tags_by_category = scrape_tags_by_category_page()?;
         let tag_details = scrape::scrape_tag_detail_pages(&tags_by_category)?;
@@ -24,6 +25,16 @@ pub fn run_factory() -> Result<()> {
         (tags, tags_categorized, tag_details)
     };
 
+    let (aria_attributes, aria_roles) = {
+        let mut aria_attributes = lookup::aria_attributes();
+        let mut aria_roles = lookup::aria_roles();
+
+        aria_attributes.sort_keys();
+        aria_roles.sort_keys();
+
+        (aria_attributes, aria_roles)
+    };
+
     let (attributes, attributes_categorized, events_attrs) = {
         let (attributes, events) = scrape::scrape_attributes_page()?;
         let global_attributes = scrape_global_attributes_page()?;
@@ -33,6 +44,15 @@ pub fn run_factory() -> Result<()> {
             attributes, global_attributes, tag_details, &attributes_categorized,
         )?;
 
+        // w3schools does not document the aria states and properties, they are global attributes
+        for (name, tags) in lookup::aria_global_attributes(&aria_attributes) {
+            attributes_categorized.entry(AttributeCategory::GlobalAttributes)
+                .or_insert_with(|| AttributeCategoryDetails { category: AttributeCategory::GlobalAttributes, attributes: HasAttributes::default() })
+                .attributes.insert(name.clone());
+
+            attributes.insert(name, tags);
+        }
+
         attributes_categorized.sort_keys();
         attributes.sort_keys();
 
@@ -42,11 +62,11 @@ pub fn run_factory() -> Result<()> {
     let (events, events_categorized, event_objects) = {
         let events = scrape::scrape_events_page()?;
         let events_by_category = scrape::scrape_events_by_category_page()?;
-        let event_objects = scrape::scrape_event_objects_page()?;
+        let event_objects = scrape::scrape_event_object_pages(&events)?;
 
+        let mut event_objects = build::event_objects(&event_objects);
         let mut events = build::build_events(events, events_attrs)?;
         let mut events_categorized = build::categorize_events(events_by_category)?;
-        let mut event_objects = build::event_objects(&event_objects)?;
 
         events.sort_keys();
         events_categorized.sort_keys();
@@ -65,21 +85,32 @@ pub fn run_factory() -> Result<()> {
         supported_browsers
     };
 
+    let countries = lookup::lookup_countries();
+    let languages = lookup::lookup_languages();
+
     let reference = WebReference {
+        format_version: FORMAT_VERSION,
+        aria_attributes,
+        aria_roles,
         attributes,
         attributes_categorized,
+        countries,
+        // w3schools is not scraped for css, the previous reference (i.e. of the reffy mode) is kept so
+        // the changelog does not report the properties as removed
+        css_categorized: previous.as_ref().map(|previous| previous.css_categorized.clone()).unwrap_or_default(),
+        css_properties: previous.as_ref().map(|previous| previous.css_properties.clone()).unwrap_or_default(),
         event_objects,
         events,
         events_categorized,
+        languages,
         supported_browsers,
+        // w3schools is not scraped for svg either, kept the same way as css
+        svg_attributes: previous.as_ref().map(|previous| previous.svg_attributes.clone()).unwrap_or_default(),
+        svg_attributes_categorized: previous.as_ref().map(|previous| previous.svg_attributes_categorized.clone()).unwrap_or_default(),
+        svg_tags: previous.as_ref().map(|previous| previous.svg_tags.clone()).unwrap_or_default(),
         tags,
         tags_categorized,
     };
 
-    let output = File::create("html-5-specs.json")?;
-    let writer = BufWriter::new(output);
-
-    serde_json::to_writer_pretty(writer, &reference)?;
-
-    Ok(())
+    write_reference(&reference, previous.as_ref())
 }
@@ -224,13 +224,19 @@ pub fn scrape_events_page() -> Result<RawEvents> {
     }).collect::<Result<RawEvents>>()
 }
 
-pub fn scrape_event_objects_page() -> Result<Vec<String>> {
-    const DOC_URL: &str = "https://www.w3schools.com/jsref/dom_obj_event.asp";
-    const DOC_TOPIC: &str = "Html DOM Events";
+pub fn scrape_event_object_pages(events: &RawEvents) -> Result<RawEventObjectPages> {
+    let objects = events.iter()
+        .flat_map(|(_event, _desc, _deprecated, _details, objects)| objects)
+        .map(|(object, url)| (object.clone(), url.clone()))
+        .collect::<RawEventObjects>();
 
-    let _root = retrieve_document(&DOC_URL, DOC_TOPIC)?;
+    objects.into_iter()
+        .map(|(object, url)| {
+            let members = scrape_event_object_page(&object, &url)?;
 
-    Ok(vec![])
+            Ok((object, url, members))
+        })
+        .collect::<Result<RawEventObjectPages>>()
 }
 
 pub fn scrape_tag_detail_pages(tags_by_category: &RawTagsByCategory) -> Result<RawTagsDetails> {
@@ -311,6 +317,82 @@ fn scrape_event_details_page(url: &Url, event: &String) -> Result<(SupportedBrow
     Ok((supported, details))
 }
 
+// properties and methods of an event object, the page lists methods with parentheses, i.e. "getModifierState()",
+// and links them to pages that list their parameters
+fn scrape_event_object_page(object: &str, url: &Url) -> Result<RawEventObjectMembers> {
+    let doc_topic = format!("{object:?} Event Object");
+
+    let root = retrieve_document(url, &doc_topic)?;
+
+    let tables = root.find(predicate::Class("w3-main")).next()
+        .ok_or_else(|| anyhow!("Could not find expected contents of {doc_topic} - {url}"))?
+        .find(predicate::Name("table").and(predicate::Class("ws-table-all")));
+
+    tables
+        .flat_map(|table| table.find(predicate::Name("tr")).skip(1))
+        .map(|row| {
+            let mut columns = row.find(predicate::Name("td"));
+
+            let (member, member_url) = parse_label_and_url(&columns.next(), UrlOption::Optional, BASE_JS_URL)
+                .map_err(|err| {
+                    let html = row.html();
+
+                    anyhow!("Exception parsing member: {doc_topic} - {url}\n  html: {html:?}\n  Err: {err}")
+                })?;
+
+            let description = columns.next()
+                .ok_or_else(|| {
+                    let html = row.html();
+
+                    anyhow!("Could not find {member:?} member's description: {doc_topic} - {url}\n  html: {html:?}")
+                })?.text();
+
+            let parameters = match member_url.filter(|_| member.contains('(')) {
+                Some(member_url) => scrape_method_parameters(&member, &member_url)?,
+                None => RawParameters::new()
+            };
+
+            Ok((member, description, parameters))
+        })
+        .collect::<Result<RawEventObjectMembers>>()
+}
+
+// names and descriptions of a method's parameters, pages of methods without parameters have no "Parameter"
+// table or list "None"
+fn scrape_method_parameters(method: &str, url: &Url) -> Result<RawParameters> {
+    const NONE: &str = "None";
+    const PARAMETER: &str = "Parameter";
+
+    let doc_topic = format!("{method} Method");
+
+    let root = retrieve_document(url, &doc_topic)?;
+
+    let main = root.find(predicate::Class("w3-main")).next()
+        .ok_or_else(|| anyhow!("Could not find expected contents of {doc_topic} - {url}"))?;
+
+    let table = main.find(predicate::Name("table").and(predicate::Class("ws-table-all")))
+        .find(|table| table.find(predicate::Name("th")).next().is_some_and(|header| header.text().trim() == PARAMETER));
+
+    let Some(table) = table else {
+        return Ok(RawParameters::new());
+    };
+
+    table.find(predicate::Name("tr")).skip(1)
+        .map(|row| {
+            let mut columns = row.find(predicate::Name("td"));
+
+            let (Some(name), Some(description)) = (columns.next(), columns.next()) else {
+                let html = row.html();
+
+                return Err(anyhow!("Could not find parameter and description: {doc_topic} - {url}\n  html: {html:?}"));
+            };
+
+            Ok((name.text().trim().to_string(), description.text().trim().to_string()))
+        })
+        .filter(|parameter| !matches!(parameter, Ok((name, _)) if name == NONE))
+        .collect::<Result<RawParameters>>()
+}
+
 fn parse_event_category(events: Node) -> Result<RawEventNames> {
     let rows = events.find(predicate::Name("tr")).skip(1);
 
@@ -0,0 +1,372 @@
+use web_reference::prelude::*;
+
+use AriaAttributeKind::{Property, State};
+use AriaValueType::{IdReference, IdReferenceList, Integer, Number, Token, TokenList, Tristate, TrueFalse, TrueFalseUndefined};
+
+const GLOBAL: &str = "global";
+
+// the attribute that sets a tag's explicit role, a space separated list of roles of which the first known one applies
+const ROLE_ATTRIBUTE: &str = "role";
+
+// the WAI-ARIA 1.2 role taxonomy, name, abstract, superclasses, required and supported states and properties
+#[allow(clippy::type_complexity)]
+const ARIA_ROLES: &[(&str, bool, &[&str], &[&str], &[&str], &str)] = &[
+    ("alert", false, &["section"], &[], &[], "Important, and usually time-sensitive, information"),
+    ("alertdialog", false, &["alert", "dialog"], &[], &[], "A dialog that interrupts the workflow to communicate an alert"),
+    ("application", false, &["structure"], &[], &["aria-activedescendant", "aria-expanded"], "A region declared as a web application, as opposed to a web document"),
+    ("article", false, &["document"], &[], &["aria-posinset", "aria-setsize"], "A self-contained composition that is independently distributable"),
+    ("banner", false, &["landmark"], &[], &[], "A landmark that contains mostly site-oriented rather than page-specific content"),
+    ("blockquote", false, &["section"], &[], &[], "A section of content that is quoted from another source"),
+    ("button", false, &["command"], &[], &["aria-expanded", "aria-pressed"], "An input that allows for user-triggered actions"),
+    ("caption", false, &["section"], &[], &[], "Visible content that names, or describes a figure, grid, table, or treegrid"),
+    ("cell", false, &["section"], &[], &["aria-colindex", "aria-colspan", "aria-rowindex", "aria-rowspan"], "A cell in a tabular container"),
+    ("checkbox", false, &["input"], &["aria-checked"], &["aria-readonly", "aria-required"], "A checkable input that has three possible values: true, false, or mixed"),
+    ("code", false, &["section"], &[], &[], "A section whose content represents a fragment of computer code"),
+    ("columnheader", false, &["cell", "gridcell", "sectionhead"], &[], &["aria-sort"], "A cell containing header information for a column"),
+    ("combobox", false, &["input"], &["aria-expanded"], &["aria-activedescendant", "aria-autocomplete", "aria-controls", "aria-readonly", "aria-required"], "An input that controls another element, such as a listbox or grid, that can dynamically pop up to help the user set the value of the input"),
+    ("command", true, &["widget"], &[], &[], "A form of widget that performs an action but does not receive input data"),
+    ("complementary", false, &["landmark"], &[], &[], "A landmark that is designed to be complementary to the main content"),
+    ("composite", true, &["widget"], &[], &["aria-activedescendant"], "A widget that may contain navigable descendants or owned children"),
+    ("contentinfo", false, &["landmark"], &[], &[], "A landmark that contains information about the parent document"),
+    ("definition", false, &["section"], &[], &[], "A definition of a term or concept"),
+    ("deletion", false, &["section"], &[], &[], "Content that is marked as removed or suggested for removal"),
+    ("dialog", false, &["window"], &[], &[], "A descendant window of the primary window of a web application"),
+    ("directory", false, &["list"], &[], &[], "A list of references to members of a group, such as a static table of contents"),
+    ("document", false, &["structure"], &[], &["aria-expanded"], "An element containing content that assistive technology users may want to browse in a reading mode"),
+    ("emphasis", false, &["section"], &[], &[], "One or more emphasized characters"),
+    ("feed", false, &["list"], &[], &[], "A scrollable list of articles where scrolling may cause articles to be added to or removed from either end of the list"),
+    ("figure", false, &["section"], &[], &[], "A perceivable section of content that typically contains a graphical document, images, code snippets, or example text"),
+    ("form", false, &["landmark"], &[], &[], "A landmark region that contains a collection of items and objects that, as a whole, combine to create a form"),
+    ("generic", false, &["structure"], &[], &[], "A nameless container element that has no semantic meaning on its own"),
+    ("grid", false, &["composite", "table"], &[], &["aria-multiselectable", "aria-readonly"], "A composite widget containing a collection of one or more rows with one or more cells"),
+    ("gridcell", false, &["cell", "widget"], &[], &["aria-readonly", "aria-required", "aria-selected"], "A cell in a grid or treegrid"),
+    ("group", false, &["section"], &[], &["aria-activedescendant"], "A set of user interface objects that is not intended to be included in a page summary or table of contents"),
+    ("heading", false, &["sectionhead"], &["aria-level"], &[], "A heading for a section of the page"),
+    ("img", false, &["section"], &[], &[], "A container for a collection of elements that form an image"),
+    ("input", true, &["widget"], &[], &[], "A generic type of widget that allows user input"),
+    ("insertion", false, &["section"], &[], &[], "Content that is marked as added or suggested for addition"),
+    ("landmark", true, &["section"], &[], &[], "A perceivable section containing content that is relevant to a specific, author-specified purpose"),
+    ("link", false, &["command"], &[], &["aria-expanded"], "An interactive reference to an internal or external resource"),
+    ("list", false, &["section"], &[], &[], "A section containing listitem elements"),
+    ("listbox", false, &["select"], &[], &["aria-expanded", "aria-multiselectable", "aria-readonly", "aria-required"], "A widget that allows the user to select one or more items from a list of choices"),
+    ("listitem", false, &["section"], &[], &["aria-level", "aria-posinset", "aria-setsize"], "A single item in a list or directory"),
+    ("log", false, &["section"], &[], &[], "A type of live region where new information is added in meaningful order and old information may disappear"),
+    ("main", false, &["landmark"], &[], &[], "A landmark containing the main content of a document"),
+    ("marquee", false, &["section"], &[], &[], "A type of live region where non-essential information changes frequently"),
+    ("math", false, &["section"], &[], &[], "Content that represents a mathematical expression"),
+    ("menu", false, &["select"], &[], &[], "A type of widget that offers a list of choices to the user"),
+    ("menubar", false, &["menu"], &[], &[], "A presentation of menu that usually remains visible and is usually presented horizontally"),
+    ("menuitem", false, &["command"], &[], &["aria-expanded", "aria-posinset", "aria-setsize"], "An option in a set of choices contained by a menu or menubar"),
+    ("menuitemcheckbox", false, &["checkbox", "menuitem"], &[], &[], "A menuitem with a checkable state whose possible values are true, false, or mixed"),
+    ("menuitemradio", false, &["menuitemcheckbox"], &[], &[], "A checkable menuitem in a set of elements with the same role, only one of which can be checked at a time"),
+    ("meter", false, &["range"], &["aria-valuenow"], &[], "An element that represents a scalar measurement within a known range, or a fractional value"),
+    ("navigation", false, &["landmark"], &[], &[], "A landmark containing a collection of navigational elements for navigating the document or related documents"),
+    ("none", false, &["structure"], &[], &[], "An element whose implicit native role semantics will not be mapped to the accessibility api"),
+    ("note", false, &["section"], &[], &[], "A section whose content is parenthetic or ancillary to the main content of the resource"),
+    ("option", false, &["input"], &["aria-selected"], &["aria-checked", "aria-posinset", "aria-setsize"], "A selectable item in a select list"),
+    ("paragraph", false, &["section"], &[], &[], "A paragraph of content"),
+    ("presentation", false, &["structure"], &[], &[], "An element whose implicit native role semantics will not be mapped to the accessibility api"),
+    ("progressbar", false, &["range", "widget"], &[], &[], "An element that displays the progress status for tasks that take a long time"),
+    ("radio", false, &["input"], &["aria-checked"], &["aria-posinset", "aria-setsize"], "A checkable input in a group of elements with the same role, only one of which can be checked at a time"),
+    ("radiogroup", false, &["select"], &[], &["aria-readonly", "aria-required"], "A group of radio buttons"),
+    ("range", true, &["structure"], &[], &["aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"], "An element representing a range of values"),
+    ("region", false, &["landmark"], &[], &[], "A landmark containing content that is relevant to a specific, author-specified purpose"),
+    ("roletype", true, &[], &[], &[], "The base role from which all other roles inherit"),
+    ("row", false, &["group", "widget"], &[], &["aria-colindex", "aria-expanded", "aria-level", "aria-posinset", "aria-rowindex", "aria-selected", "aria-setsize"], "A row of cells in a tabular container"),
+    ("rowgroup", false, &["structure"], &[], &[], "A structure containing one or more row elements in a tabular container"),
+    ("rowheader", false, &["cell", "gridcell", "sectionhead"], &[], &["aria-sort"], "A cell containing header information for a row"),
+    ("scrollbar", false, &["range", "widget"], &["aria-controls", "aria-valuenow"], &["aria-orientation"], "A graphical object that controls the scrolling of content within a viewing area"),
+    ("search", false, &["landmark"], &[], &[], "A landmark region that contains a collection of items and objects that, as a whole, combine to create a search facility"),
+    ("searchbox", false, &["textbox"], &[], &[], "A type of textbox intended for specifying search criteria"),
+    ("section", true, &["structure"], &[], &[], "A renderable structural containment unit in a document or application"),
+    ("sectionhead", true, &["structure"], &[], &[], "A structure that labels or summarizes the topic of its related section"),
+    ("select", true, &["composite", "group"], &[], &["aria-orientation"], "A form widget that allows the user to make selections from a set of choices"),
+    ("separator", false, &["structure", "widget"], &[], &["aria-orientation", "aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"], "A divider that separates and distinguishes sections of content or groups of menuitems"),
+    ("slider", false, &["input", "range"], &["aria-valuenow"], &["aria-orientation", "aria-readonly"], "An input where the user selects a value from within a given range"),
+    ("spinbutton", false, &["composite", "input", "range"], &[], &["aria-readonly", "aria-required"], "A form of range that expects the user to select from among discrete choices"),
+    ("status", false, &["section"], &[], &[], "A type of live region whose content is advisory information for the user but is not important enough to justify an alert"),
+    ("strong", false, &["section"], &[], &[], "Content which is important, serious, or urgent"),
+    ("structure", true, &["roletype"], &[], &[], "A document structural element"),
+    ("subscript", false, &["section"], &[], &[], "One or more subscripted characters"),
+    ("superscript", false, &["section"], &[], &[], "One or more superscripted characters"),
+    ("switch", false, &["checkbox"], &[], &[], "A type of checkbox that represents on and off values, as opposed to checked and unchecked values"),
+    ("tab", false, &["sectionhead", "widget"], &[], &["aria-expanded", "aria-posinset", "aria-selected", "aria-setsize"], "A grouping label providing a mechanism for selecting the tab content that is to be rendered to the user"),
+    ("table", false, &["section"], &[], &["aria-colcount", "aria-rowcount"], "A section containing data arranged in rows and columns"),
+    ("tablist", false, &["composite"], &[], &["aria-multiselectable", "aria-orientation"], "A list of tab elements, which are references to tabpanel elements"),
+    ("tabpanel", false, &["section"], &[], &[], "A container for the resources associated with a tab"),
+    ("term", false, &["section"], &[], &[], "A word or phrase with an optional corresponding definition"),
+    ("textbox", false, &["input"], &[], &["aria-activedescendant", "aria-autocomplete", "aria-multiline", "aria-placeholder", "aria-readonly", "aria-required"], "A type of input that allows free-form text as its value"),
+    ("time", false, &["section"], &[], &[], "An element that represents a specific point in time"),
+    ("timer", false, &["status"], &[], &[], "A type of live region containing a numerical counter which indicates an amount of elapsed time from a start point, or the time remaining until an end point"),
+    ("toolbar", false, &["group"], &[], &["aria-orientation"], "A collection of commonly used function buttons or controls represented in compact visual form"),
+    ("tooltip", false, &["section"], &[], &[], "A contextual popup that displays a description for an element"),
+    ("tree", false, &["select"], &[], &["aria-multiselectable", "aria-required"], "A widget that allows the user to select one or more items from a hierarchically organized collection"),
+    ("treegrid", false, &["grid", "tree"], &[], &[], "A grid whose rows can be expanded and collapsed in the same manner as for a tree"),
+    ("treeitem", false, &["listitem", "option"], &[], &["aria-expanded"], "An option item of a tree"),
+    ("widget", true, &["roletype"], &[], &[], "An interactive component of a graphical user interface"),
+    ("window", true, &["roletype"], &[], &["aria-modal"], "A browser or application window"),
+];
+
+// the WAI-ARIA 1.2 states and properties, name, kind, value type, tokens, global, deprecated
+#[allow(clippy::type_complexity)]
+const ARIA_ATTRIBUTES: &[(&str, AriaAttributeKind, AriaValueType, &[&str], bool, bool, &str)] = &[
+    ("aria-activedescendant", Property, IdReference, &[], false, false, "Identifies the currently active element when focus is on a composite widget, combobox, textbox, group, or application"),
+    ("aria-atomic", Property, TrueFalse, &[], true, false, "Indicates whether assistive technologies will present all, or only parts of, the changed region"),
+    ("aria-autocomplete", Property, Token, &["inline", "list", "both", "none"], false, false, "Indicates whether inputting text could trigger display of one or more predictions of the user's intended value"),
+    ("aria-busy", State, TrueFalse, &[], true, false, "Indicates an element is being modified and that assistive technologies may want to wait until the modifications are complete"),
+    ("aria-checked", State, Tristate, &[], false, false, "Indicates the current checked state of checkboxes, radio buttons, and other widgets"),
+    ("aria-colcount", Property, Integer, &[], false, false, "Defines the total number of columns in a table, grid, or treegrid"),
+    ("aria-colindex", Property, Integer, &[], false, false, "Defines an element's column index or position with respect to the total number of columns"),
+    ("aria-colspan", Property, Integer, &[], false, false, "Defines the number of columns spanned by a cell or gridcell"),
+    ("aria-controls", Property, IdReferenceList, &[], true, false, "Identifies the elements whose contents or presence are controlled by the current element"),
+    ("aria-current", State, Token, &["page", "step", "location", "date", "time", "true", "false"], true, false, "Indicates the element that represents the current item within a container or set of related elements"),
+    ("aria-describedby", Property, IdReferenceList, &[], true, false, "Identifies the elements that describe the object"),
+    ("aria-details", Property, IdReference, &[], true, false, "Identifies the element that provides a detailed, extended description for the object"),
+    ("aria-disabled", State, TrueFalse, &[], true, false, "Indicates that the element is perceivable but disabled, so it is not editable or otherwise operable"),
+    ("aria-dropeffect", Property, TokenList, &["copy", "execute", "link", "move", "none", "popup"], true, true, "Indicates what functions can be performed when a dragged object is released on the drop target"),
+    ("aria-errormessage", Property, IdReference, &[], true, false, "Identifies the element that provides an error message for an object"),
+    ("aria-expanded", State, TrueFalseUndefined, &[], false, false, "Indicates whether a grouping element owned or controlled by this element is expanded or collapsed"),
+    ("aria-flowto", Property, IdReferenceList, &[], true, false, "Identifies the next elements in an alternate reading order of content"),
+    ("aria-grabbed", State, TrueFalseUndefined, &[], true, true, "Indicates an element's grabbed state in a drag-and-drop operation"),
+    ("aria-haspopup", Property, Token, &["false", "true", "menu", "listbox", "tree", "grid", "dialog"], true, false, "Indicates the availability and type of interactive popup element that can be triggered by an element"),
+    ("aria-hidden", State, TrueFalseUndefined, &[], true, false, "Indicates whether the element is exposed to an accessibility api"),
+    ("aria-invalid", State, Token, &["grammar", "false", "spelling", "true"], true, false, "Indicates the entered value does not conform to the format expected by the application"),
+    ("aria-keyshortcuts", Property, AriaValueType::String, &[], true, false, "Indicates keyboard shortcuts that an author has implemented to activate or give focus to an element"),
+    ("aria-label", Property, AriaValueType::String, &[], true, false, "Defines a string value that labels the current element"),
+    ("aria-labelledby", Property, IdReferenceList, &[], true, false, "Identifies the elements that label the current element"),
+    ("aria-level", Property, Integer, &[], false, false, "Defines the hierarchical level of an element within a structure"),
+    ("aria-live", Property, Token, &["assertive", "off", "polite"], true, false, "Indicates that an element will be updated, and describes the types of updates to expect"),
+    ("aria-modal", Property, TrueFalse, &[], false, false, "Indicates whether an element is modal when displayed"),
+    ("aria-multiline", Property, TrueFalse, &[], false, false, "Indicates whether a text box accepts multiple lines of input or only a single line"),
+    ("aria-multiselectable", Property, TrueFalse, &[], false, false, "Indicates that the user may select more than one item from the current selectable descendants"),
+    ("aria-orientation", Property, Token, &["horizontal", "undefined", "vertical"], false, false, "Indicates whether the element's orientation is horizontal, vertical, or unknown"),
+    ("aria-owns", Property, IdReferenceList, &[], true, false, "Identifies elements that define a visual, functional, or contextual parent and child relationship the dom cannot represent"),
+    ("aria-placeholder", Property, AriaValueType::String, &[], false, false, "Defines a short hint intended to aid the user with data entry when the control has no value"),
+    ("aria-posinset", Property, Integer, &[], false, false, "Defines an element's number or position in the current set of listitems or treeitems"),
+    ("aria-pressed", State, Tristate, &[], false, false, "Indicates the current pressed state of toggle buttons"),
+    ("aria-readonly", Property, TrueFalse, &[], false, false, "Indicates that the element is not editable, but is otherwise operable"),
+    ("aria-relevant", Property, TokenList, &["additions", "all", "removals", "text"], true, false, "Indicates what notifications the user agent will trigger when the accessibility tree within a live region is modified"),
+    ("aria-required", Property, TrueFalse, &[], false, false, "Indicates that user input is required on the element before a form may be submitted"),
+    ("aria-roledescription", Property, AriaValueType::String, &[], true, false, "Defines a human-readable, author-localized description for the role of an element"),
+    ("aria-rowcount", Property, Integer, &[], false, false, "Defines the total number of rows in a table, grid, or treegrid"),
+    ("aria-rowindex", Property, Integer, &[], false, false, "Defines an element's row index or position with respect to the total number of rows"),
+    ("aria-rowspan", Property, Integer, &[], false, false, "Defines the number of rows spanned by a cell or gridcell"),
+    ("aria-selected", State, TrueFalseUndefined, &[], false, false, "Indicates the current selected state of various widgets"),
+    ("aria-setsize", Property, Integer, &[], false, false, "Defines the number of items in the current set of listitems or treeitems"),
+    ("aria-sort", Property, Token, &["ascending", "descending", "none", "other"], false, false, "Indicates if items in a table or grid are sorted in ascending or descending order"),
+    ("aria-valuemax", Property, Number, &[], false, false, "Defines the maximum allowed value for a range widget"),
+    ("aria-valuemin", Property, Number, &[], false, false, "Defines the minimum allowed value for a range widget"),
+    ("aria-valuenow", Property, Number, &[], false, false, "Defines the current value for a range widget"),
+    ("aria-valuetext", Property, AriaValueType::String, &[], false, false, "Defines the human readable text alternative of aria-valuenow for a range widget"),
+];
+
+// the roles an html tag allows, tags missing from `TAG_ARIA` allow any role
+enum Allowed {
+    Any,
+    No,
+    Only(&'static [&'static str]),
+}
+
+// roles that list containers, i.e. `<ul>`, allow
+const LIST_ROLES: &[&str] = &["directory", "group", "listbox", "menu", "menubar", "none", "presentation", "radiogroup", "tablist", "toolbar", "tree"];
+
+const HEADING_ROLES: &[&str] = &["none", "presentation", "tab"];
+
+const EMBEDDED_ROLES: &[&str] = &["application", "document", "img", "none", "presentation"];
+
+const NO_SEMANTICS_ROLES: &[&str] = &["none", "presentation"];
+
+// ARIA in HTML, the implicit role and allowed roles of html tags, for `<a>` with a `href`, `<img>` with
+// a non empty `alt` and `<input>` of type `text`
+const TAG_ARIA: &[(&str, Option<&str>, Allowed)] = &[
+    ("a", Some("link"), Allowed::Only(&["button", "checkbox", "menuitem", "menuitemcheckbox", "menuitemradio", "option", "radio", "switch", "tab", "treeitem"])),
+    ("address", Some("group"), Allowed::Any),
+    ("area", Some("link"), Allowed::No),
+    ("article", Some("article"), Allowed::Only(&["application", "document", "feed", "main", "none", "presentation", "region"])),
+    ("aside", Some("complementary"), Allowed::Only(&["feed", "none", "note", "presentation", "region", "search"])),
+    ("audio", None, Allowed::Only(&["application"])),
+    ("b", Some("generic"), Allowed::Any),
+    ("base", None, Allowed::No),
+    ("bdi", Some("generic"), Allowed::Any),
+    ("bdo", Some("generic"), Allowed::Any),
+    ("blockquote", Some("blockquote"), Allowed::Any),
+    ("body", Some("generic"), Allowed::No),
+    ("br", None, Allowed::Only(NO_SEMANTICS_ROLES)),
+    ("button", Some("button"), Allowed::Only(&["checkbox", "combobox", "gridcell", "link", "menuitem", "menuitemcheckbox", "menuitemradio", "option", "radio", "separator", "slider", "switch", "tab", "treeitem"])),
+    ("caption", Some("caption"), Allowed::No),
+    ("code", Some("code"), Allowed::Any),
+    ("col", None, Allowed::No),
+    ("colgroup", None, Allowed::No),
+    ("data", Some("generic"), Allowed::Any),
+    ("datalist", Some("listbox"), Allowed::No),
+    ("dd", Some("definition"), Allowed::No),
+    ("del", Some("deletion"), Allowed::Any),
+    ("details", Some("group"), Allowed::No),
+    ("dfn", Some("term"), Allowed::Any),
+    ("dialog", Some("dialog"), Allowed::Only(&["alertdialog"])),
+    ("div", Some("generic"), Allowed::Any),
+    ("dl", None, Allowed::Only(&["group", "list", "none", "presentation"])),
+    ("dt", Some("term"), Allowed::Only(&["listitem"])),
+    ("em", Some("emphasis"), Allowed::Any),
+    ("embed", None, Allowed::Only(EMBEDDED_ROLES)),
+    ("fieldset", Some("group"), Allowed::Only(&["none", "presentation", "radiogroup"])),
+    ("figcaption", None, Allowed::Only(&["group", "none", "presentation"])),
+    ("figure", Some("figure"), Allowed::Any),
+    ("footer", Some("contentinfo"), Allowed::Only(&["group", "none", "presentation"])),
+    ("form", Some("form"), Allowed::Only(&["none", "presentation", "search"])),
+    ("h1", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("h2", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("h3", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("h4", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("h5", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("h6", Some("heading"), Allowed::Only(HEADING_ROLES)),
+    ("head", None, Allowed::No),
+    ("header", Some("banner"), Allowed::Only(&["group", "none", "presentation"])),
+    ("hr", Some("separator"), Allowed::Only(NO_SEMANTICS_ROLES)),
+    ("html", Some("document"), Allowed::No),
+    ("i", Some("generic"), Allowed::Any),
+    ("iframe", None, Allowed::Only(EMBEDDED_ROLES)),
+    ("img", Some("img"), Allowed::Only(&["button", "checkbox", "link", "menuitem", "menuitemcheckbox", "menuitemradio", "meter", "option", "progressbar", "radio", "scrollbar", "separator", "slider", "switch", "tab", "treeitem"])),
+    ("input", Some("textbox"), Allowed::Only(&["combobox", "searchbox", "spinbutton"])),
+    ("ins", Some("insertion"), Allowed::Any),
+    ("label", None, Allowed::No),
+    ("legend", None, Allowed::No),
+    ("li", Some("listitem"), Allowed::Only(&["menuitem", "menuitemcheckbox", "menuitemradio", "none", "option", "presentation", "radio", "separator", "tab", "treeitem"])),
+    ("link", None, Allowed::No),
+    ("main", Some("main"), Allowed::No),
+    ("map", None, Allowed::No),
+    ("meta", None, Allowed::No),
+    ("meter", Some("meter"), Allowed::No),
+    ("nav", Some("navigation"), Allowed::Only(&["menu", "menubar", "none", "presentation", "tablist"])),
+    ("noscript", None, Allowed::No),
+    ("object", None, Allowed::Only(&["application", "document", "img"])),
+    ("ol", Some("list"), Allowed::Only(LIST_ROLES)),
+    ("optgroup", Some("group"), Allowed::No),
+    ("option", Some("option"), Allowed::No),
+    ("output", Some("status"), Allowed::Any),
+    ("p", Some("paragraph"), Allowed::Any),
+    ("param", None, Allowed::No),
+    ("picture", None, Allowed::No),
+    ("pre", Some("generic"), Allowed::Any),
+    ("progress", Some("progressbar"), Allowed::No),
+    ("q", Some("generic"), Allowed::Any),
+    ("s", Some("deletion"), Allowed::Any),
+    ("samp", Some("generic"), Allowed::Any),
+    ("script", None, Allowed::No),
+    ("section", Some("region"), Allowed::Only(&["alert", "alertdialog", "application", "banner", "complementary", "contentinfo", "dialog", "document", "feed", "group", "log", "main", "marquee", "navigation", "none", "note", "presentation", "search", "status", "tabpanel"])),
+    ("select", Some("combobox"), Allowed::Only(&["menu"])),
+    ("small", Some("generic"), Allowed::Any),
+    ("source", None, Allowed::No),
+    ("span", Some("generic"), Allowed::Any),
+    ("strong", Some("strong"), Allowed::Any),
+    ("style", None, Allowed::No),
+    ("sub", Some("subscript"), Allowed::Any),
+    ("summary", None, Allowed::No),
+    ("sup", Some("superscript"), Allowed::Any),
+    ("table", Some("table"), Allowed::Any),
+    ("tbody", Some("rowgroup"), Allowed::Any),
+    ("td", Some("cell"), Allowed::Any),
+    ("template", None, Allowed::No),
+    ("textarea", Some("textbox"), Allowed::No),
+    ("tfoot", Some("rowgroup"), Allowed::Any),
+    ("th", Some("columnheader"), Allowed::Any),
+    ("thead", Some("rowgroup"), Allowed::Any),
+    ("time", Some("time"), Allowed::Any),
+    ("title", None, Allowed::No),
+    ("tr", Some("row"), Allowed::Any),
+    ("track", None, Allowed::No),
+    ("u", Some("generic"), Allowed::Any),
+    ("ul", Some("list"), Allowed::Only(LIST_ROLES)),
+    ("video", None, Allowed::Only(&["application"])),
+    ("wbr", None, Allowed::Only(NO_SEMANTICS_ROLES)),
+];
+
+pub fn aria_roles() -> AriaRoles {
+    ARIA_ROLES.iter()
+        .map(|(name, is_abstract, superclasses, required, supported, description)| (name.to_string(), AriaRole {
+            name: name.to_string(),
+            description: Description::from(*description),
+            is_abstract: *is_abstract,
+            superclasses: superclasses.iter().map(ToString::to_string).collect(),
+            required_attributes: required.iter().map(ToString::to_string).collect(),
+            supported_attributes: supported.iter().map(ToString::to_string).collect(),
+        }))
+        .collect()
+}
+
+pub fn aria_attributes() -> AriaAttributes {
+    ARIA_ATTRIBUTES.iter()
+        .map(|(name, kind, value_type, tokens, global, deprecated, description)| (name.to_string(), AriaAttribute {
+            name: name.to_string(),
+            description: Description::from(*description),
+            kind: *kind,
+            value_type: *value_type,
+            tokens: tokens.iter().map(ToString::to_string).collect(),
+            global: *global,
+            deprecated: *deprecated,
+        }))
+        .collect()
+}
+
+// the html attributes of the aria states and properties and `role`, every tag accepts them
+pub fn aria_global_attributes(aria_attributes: &AriaAttributes) -> Attributes {
+    let role = Attribute {
+        name: ROLE_ATTRIBUTE.to_string(),
+        belongs_to: AttributeBelongsTo::Global,
+        description: Description::from("Specifies the accessibility role of an element"),
+        supported: SupportedBrowsers::default(),
+        values: AttributeValue::Text { description: Description::from("A space separated list of aria roles") },
+    };
+
+    let attributes = aria_attributes.values()
+        .map(|aria| Attribute {
+            name: aria.name.clone(),
+            belongs_to: AttributeBelongsTo::Global,
+            description: aria.description.clone(),
+            supported: SupportedBrowsers::default(),
+            values: aria_attribute_value(aria),
+        });
+
+    std::iter::once(role).chain(attributes)
+        .map(|attribute| (attribute.name.clone(), std::iter::once((GLOBAL.to_string(), attribute)).collect()))
+        .collect()
+}
+
+// the implicit and allowed roles of an html tag
+pub fn tag_aria(tag: &str) -> TagAria {
+    let Some((_, implicit_role, allowed)) = TAG_ARIA.iter().find(|(name, _, _)| *name == tag) else {
+        return TagAria::default();
+    };
+
+    TagAria {
+        implicit_role: implicit_role.map(ToString::to_string),
+        allowed_roles: match allowed {
+            Allowed::Any => AllowedAriaRoles::Any,
+            Allowed::No => AllowedAriaRoles::None,
+            Allowed::Only(roles) => AllowedAriaRoles::Roles(roles.iter().map(ToString::to_string).collect()),
+        },
+    }
+}
+
+fn aria_attribute_value(aria: &AriaAttribute) -> AttributeValue {
+    let description = Description::default();
+
+    let keywords = match aria.value_type {
+        TrueFalse | TrueFalseUndefined | Tristate =>
+            aria.value_type.keywords().iter().map(ToString::to_string).collect::<Vec<_>>(),
+        Token =>
+            aria.tokens.iter().cloned().collect(),
+        IdReference =>
+            return AttributeValue::Id { description },
+        Integer | Number =>
+            return AttributeValue::Number { description },
+        IdReferenceList | AriaValueType::String | TokenList =>
+            return AttributeValue::Text { description },
+    };
+
+    AttributeValue::Values(
+        keywords.into_iter()
+            .map(|keyword| (keyword.clone(), Value { name: keyword, description: Description::default() }))
+            .collect()
+    )
+}
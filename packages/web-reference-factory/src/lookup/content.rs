@@ -0,0 +1,172 @@
+use web_reference::prelude::*;
+
+use ContentCategory::{Embedded, Flow, Heading, Interactive, Metadata, Palpable, Phrasing, ScriptSupporting, Sectioning};
+use PermittedContent::{Any, Elements, Nothing, Text, Transparent};
+
+// a row of `CONTENT_MODELS`, see `ContentModel` for the meaning of its fields
+struct Model {
+    tag: &'static str,
+    categories: &'static [ContentCategory],
+    content: PermittedContent,
+    content_categories: &'static [ContentCategory],
+    content_tags: &'static [&'static str],
+    excluded_categories: &'static [ContentCategory],
+    excluded_tags: &'static [&'static str],
+    parents: &'static [&'static str],
+}
+
+const EMPTY: Model = Model {
+    tag: "",
+    categories: &[],
+    content: Elements,
+    content_categories: &[],
+    content_tags: &[],
+    excluded_categories: &[],
+    excluded_tags: &[],
+    parents: &[],
+};
+
+const FLOW: &[ContentCategory] = &[Flow, Palpable];
+const PHRASING: &[ContentCategory] = &[Flow, Phrasing, Palpable];
+const SECTIONING: &[ContentCategory] = &[Flow, Sectioning, Palpable];
+const HEADING: &[ContentCategory] = &[Flow, Heading, Palpable];
+const INTERACTIVE: &[ContentCategory] = &[Flow, Phrasing, Interactive, Palpable];
+const EMBEDDED: &[ContentCategory] = &[Flow, Phrasing, Embedded, Palpable];
+const MEDIA: &[ContentCategory] = &[Flow, Phrasing, Embedded, Interactive, Palpable];
+const METADATA_PHRASING: &[ContentCategory] = &[Metadata, Flow, Phrasing];
+const SCRIPT_SUPPORTING: &[ContentCategory] = &[Metadata, Flow, Phrasing, ScriptSupporting];
+
+const HEADING_SECTIONING: &[ContentCategory] = &[Heading, Sectioning];
+const HEADER_FOOTER: &[&str] = &["footer", "header"];
+
+// the content models of the html standard, simplified to what they are without attributes, i.e. `<img>` is
+// not interactive without a `usemap`, obsolete tags have none, parents are tags of the reference, which has
+// no `<menu>` to parent an `<li>`
+const CONTENT_MODELS: &[Model] = &[
+    Model { tag: "a", categories: INTERACTIVE, content: Transparent, excluded_categories: &[Interactive], excluded_tags: &["a"], ..EMPTY },
+    Model { tag: "abbr", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "address", categories: FLOW, content_categories: &[Flow], excluded_categories: HEADING_SECTIONING, excluded_tags: &["address", "footer", "header"], ..EMPTY },
+    Model { tag: "area", categories: &[Flow, Phrasing], content: Nothing, ..EMPTY },
+    Model { tag: "article", categories: SECTIONING, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "aside", categories: SECTIONING, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "audio", categories: MEDIA, content: Transparent, content_tags: &["source", "track"], excluded_tags: &["audio", "video"], ..EMPTY },
+    Model { tag: "b", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "base", categories: &[Metadata], content: Nothing, parents: &["head"], ..EMPTY },
+    Model { tag: "bdi", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "bdo", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "blockquote", categories: FLOW, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "body", content_categories: &[Flow], parents: &["html"], ..EMPTY },
+    Model { tag: "br", categories: &[Flow, Phrasing], content: Nothing, ..EMPTY },
+    Model { tag: "button", categories: INTERACTIVE, content_categories: &[Phrasing], excluded_categories: &[Interactive], ..EMPTY },
+    Model { tag: "canvas", categories: EMBEDDED, content: Transparent, excluded_categories: &[Interactive], ..EMPTY },
+    Model { tag: "caption", content_categories: &[Flow], excluded_tags: &["table"], parents: &["table"], ..EMPTY },
+    Model { tag: "cite", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "code", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "col", content: Nothing, parents: &["colgroup"], ..EMPTY },
+    Model { tag: "colgroup", content_tags: &["col", "template"], parents: &["table"], ..EMPTY },
+    Model { tag: "data", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "datalist", categories: &[Flow, Phrasing], content_categories: &[Phrasing, ScriptSupporting], content_tags: &["option"], ..EMPTY },
+    Model { tag: "dd", content_categories: &[Flow], parents: &["dl", "div"], ..EMPTY },
+    Model { tag: "del", categories: PHRASING, content: Transparent, ..EMPTY },
+    Model { tag: "details", categories: &[Flow, Interactive, Palpable], content_categories: &[Flow], content_tags: &["summary"], ..EMPTY },
+    Model { tag: "dfn", categories: PHRASING, content_categories: &[Phrasing], excluded_tags: &["dfn"], ..EMPTY },
+    Model { tag: "dialog", categories: &[Flow], content_categories: &[Flow], ..EMPTY },
+    Model { tag: "div", categories: FLOW, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "dl", categories: FLOW, content_categories: &[ScriptSupporting], content_tags: &["dd", "div", "dt"], ..EMPTY },
+    Model { tag: "dt", content_categories: &[Flow], excluded_categories: HEADING_SECTIONING, excluded_tags: HEADER_FOOTER, parents: &["dl", "div"], ..EMPTY },
+    Model { tag: "em", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "embed", categories: MEDIA, content: Nothing, ..EMPTY },
+    Model { tag: "fieldset", categories: FLOW, content_categories: &[Flow], content_tags: &["legend"], ..EMPTY },
+    Model { tag: "figcaption", content_categories: &[Flow], parents: &["figure"], ..EMPTY },
+    Model { tag: "figure", categories: FLOW, content_categories: &[Flow], content_tags: &["figcaption"], ..EMPTY },
+    Model { tag: "footer", categories: FLOW, content_categories: &[Flow], excluded_tags: HEADER_FOOTER, ..EMPTY },
+    Model { tag: "form", categories: FLOW, content_categories: &[Flow], excluded_tags: &["form"], ..EMPTY },
+    Model { tag: "h1", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "h2", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "h3", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "h4", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "h5", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "h6", categories: HEADING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "head", content_categories: &[Metadata], parents: &["html"], ..EMPTY },
+    Model { tag: "header", categories: FLOW, content_categories: &[Flow], excluded_tags: HEADER_FOOTER, ..EMPTY },
+    Model { tag: "hr", categories: &[Flow], content: Nothing, ..EMPTY },
+    Model { tag: "html", content_tags: &["body", "head"], ..EMPTY },
+    Model { tag: "i", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "iframe", categories: MEDIA, content: Nothing, ..EMPTY },
+    Model { tag: "img", categories: EMBEDDED, content: Nothing, ..EMPTY },
+    Model { tag: "input", categories: INTERACTIVE, content: Nothing, ..EMPTY },
+    Model { tag: "ins", categories: PHRASING, content: Transparent, ..EMPTY },
+    Model { tag: "kbd", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "label", categories: INTERACTIVE, content_categories: &[Phrasing], excluded_tags: &["label"], ..EMPTY },
+    Model { tag: "legend", content_categories: &[Phrasing, Heading], parents: &["fieldset"], ..EMPTY },
+    Model { tag: "li", content_categories: &[Flow], parents: &["ol", "ul"], ..EMPTY },
+    Model { tag: "link", categories: METADATA_PHRASING, content: Nothing, ..EMPTY },
+    Model { tag: "main", categories: FLOW, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "map", categories: PHRASING, content: Transparent, content_tags: &["area"], ..EMPTY },
+    Model { tag: "mark", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "meta", categories: METADATA_PHRASING, content: Nothing, ..EMPTY },
+    Model { tag: "meter", categories: PHRASING, content_categories: &[Phrasing], excluded_tags: &["meter"], ..EMPTY },
+    Model { tag: "nav", categories: SECTIONING, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "noscript", categories: METADATA_PHRASING, content: Transparent, excluded_tags: &["noscript"], ..EMPTY },
+    Model { tag: "object", categories: EMBEDDED, content: Transparent, content_tags: &["param"], ..EMPTY },
+    Model { tag: "ol", categories: FLOW, content_categories: &[ScriptSupporting], content_tags: &["li"], ..EMPTY },
+    Model { tag: "optgroup", content_categories: &[ScriptSupporting], content_tags: &["option"], parents: &["select"], ..EMPTY },
+    Model { tag: "option", content: Text, parents: &["datalist", "optgroup", "select"], ..EMPTY },
+    Model { tag: "output", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "p", categories: FLOW, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "param", content: Nothing, parents: &["object"], ..EMPTY },
+    Model { tag: "picture", categories: &[Flow, Phrasing, Embedded], content_categories: &[ScriptSupporting], content_tags: &["img", "source"], ..EMPTY },
+    Model { tag: "pre", categories: FLOW, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "progress", categories: PHRASING, content_categories: &[Phrasing], excluded_tags: &["progress"], ..EMPTY },
+    Model { tag: "q", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "rp", content: Text, parents: &["ruby"], ..EMPTY },
+    Model { tag: "rt", content_categories: &[Phrasing], parents: &["ruby"], ..EMPTY },
+    Model { tag: "ruby", categories: PHRASING, content_categories: &[Phrasing], content_tags: &["rp", "rt"], ..EMPTY },
+    Model { tag: "s", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "samp", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "script", categories: SCRIPT_SUPPORTING, content: Text, ..EMPTY },
+    Model { tag: "section", categories: SECTIONING, content_categories: &[Flow], ..EMPTY },
+    Model { tag: "select", categories: INTERACTIVE, content_categories: &[ScriptSupporting], content_tags: &["optgroup", "option"], ..EMPTY },
+    Model { tag: "small", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "source", content: Nothing, parents: &["audio", "picture", "video"], ..EMPTY },
+    Model { tag: "span", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "strong", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "style", categories: &[Metadata], content: Text, ..EMPTY },
+    Model { tag: "sub", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "summary", content_categories: &[Phrasing, Heading], parents: &["details"], ..EMPTY },
+    Model { tag: "sup", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    // the content of an inline `<svg>` is in the svg namespace, see `Namespace`
+    Model { tag: "svg", categories: EMBEDDED, ..EMPTY },
+    Model { tag: "table", categories: FLOW, content_categories: &[ScriptSupporting], content_tags: &["caption", "colgroup", "tbody", "tfoot", "thead", "tr"], ..EMPTY },
+    Model { tag: "tbody", content_categories: &[ScriptSupporting], content_tags: &["tr"], parents: &["table"], ..EMPTY },
+    Model { tag: "td", content_categories: &[Flow], parents: &["tr"], ..EMPTY },
+    Model { tag: "template", categories: SCRIPT_SUPPORTING, content: Any, ..EMPTY },
+    Model { tag: "textarea", categories: INTERACTIVE, content: Text, ..EMPTY },
+    Model { tag: "tfoot", content_categories: &[ScriptSupporting], content_tags: &["tr"], parents: &["table"], ..EMPTY },
+    Model { tag: "th", content_categories: &[Flow], excluded_categories: HEADING_SECTIONING, excluded_tags: HEADER_FOOTER, parents: &["tr"], ..EMPTY },
+    Model { tag: "thead", content_categories: &[ScriptSupporting], content_tags: &["tr"], parents: &["table"], ..EMPTY },
+    Model { tag: "time", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "title", categories: &[Metadata], content: Text, parents: &["head"], ..EMPTY },
+    Model { tag: "tr", content_categories: &[ScriptSupporting], content_tags: &["td", "th"], parents: &["table", "tbody", "tfoot", "thead"], ..EMPTY },
+    Model { tag: "track", content: Nothing, parents: &["audio", "video"], ..EMPTY },
+    Model { tag: "u", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "ul", categories: FLOW, content_categories: &[ScriptSupporting], content_tags: &["li"], ..EMPTY },
+    Model { tag: "var", categories: PHRASING, content_categories: &[Phrasing], ..EMPTY },
+    Model { tag: "video", categories: MEDIA, content: Transparent, content_tags: &["source", "track"], excluded_tags: &["audio", "video"], ..EMPTY },
+    Model { tag: "wbr", categories: &[Flow, Phrasing], content: Nothing, ..EMPTY },
+];
+
+// the content model of an html tag, none for obsolete tags
+pub fn content_model(tag: &str) -> Option<ContentModel> {
+    let model = CONTENT_MODELS.iter().find(|model| model.tag == tag)?;
+
+    Some(ContentModel {
+        categories: model.categories.iter().copied().collect(),
+        content: model.content,
+        content_categories: model.content_categories.iter().copied().collect(),
+        content_tags: model.content_tags.iter().map(ToString::to_string).collect(),
+        excluded_categories: model.excluded_categories.iter().copied().collect(),
+        excluded_tags: model.excluded_tags.iter().map(ToString::to_string).collect(),
+        parents: model.parents.iter().map(ToString::to_string).collect(),
+    })
+}
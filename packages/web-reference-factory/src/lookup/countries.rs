@@ -0,0 +1,273 @@
+use web_reference::prelude::*;
+
+// the ISO 3166-1 countries of the debian iso-codes 4.15.0 package, as their two letter, three letter and numeric
+// codes, name and official name if it differs, i.e. `United Kingdom of Great Britain and Northern Ireland`
+// for `United Kingdom`
+static ISO_3166_1: [(&str, &str, &str, &str, Option<&str>); 249] = [
+    ("AW", "ABW", "533", "Aruba", None),
+    ("AF", "AFG", "004", "Afghanistan", Some("Islamic Republic of Afghanistan")),
+    ("AO", "AGO", "024", "Angola", Some("Republic of Angola")),
+    ("AI", "AIA", "660", "Anguilla", None),
+    ("AX", "ALA", "248", "Åland Islands", None),
+    ("AL", "ALB", "008", "Albania", Some("Republic of Albania")),
+    ("AD", "AND", "020", "Andorra", Some("Principality of Andorra")),
+    ("AE", "ARE", "784", "United Arab Emirates", None),
+    ("AR", "ARG", "032", "Argentina", Some("Argentine Republic")),
+    ("AM", "ARM", "051", "Armenia", Some("Republic of Armenia")),
+    ("AS", "ASM", "016", "American Samoa", None),
+    ("AQ", "ATA", "010", "Antarctica", None),
+    ("TF", "ATF", "260", "French Southern Territories", None),
+    ("AG", "ATG", "028", "Antigua and Barbuda", None),
+    ("AU", "AUS", "036", "Australia", None),
+    ("AT", "AUT", "040", "Austria", Some("Republic of Austria")),
+    ("AZ", "AZE", "031", "Azerbaijan", Some("Republic of Azerbaijan")),
+    ("BI", "BDI", "108", "Burundi", Some("Republic of Burundi")),
+    ("BE", "BEL", "056", "Belgium", Some("Kingdom of Belgium")),
+    ("BJ", "BEN", "204", "Benin", Some("Republic of Benin")),
+    ("BQ", "BES", "535", "Bonaire, Sint Eustatius and Saba", None),
+    ("BF", "BFA", "854", "Burkina Faso", None),
+    ("BD", "BGD", "050", "Bangladesh", Some("People's Republic of Bangladesh")),
+    ("BG", "BGR", "100", "Bulgaria", Some("Republic of Bulgaria")),
+    ("BH", "BHR", "048", "Bahrain", Some("Kingdom of Bahrain")),
+    ("BS", "BHS", "044", "Bahamas", Some("Commonwealth of the Bahamas")),
+    ("BA", "BIH", "070", "Bosnia and Herzegovina", Some("Republic of Bosnia and Herzegovina")),
+    ("BL", "BLM", "652", "Saint Barthélemy", None),
+    ("BY", "BLR", "112", "Belarus", Some("Republic of Belarus")),
+    ("BZ", "BLZ", "084", "Belize", None),
+    ("BM", "BMU", "060", "Bermuda", None),
+    ("BO", "BOL", "068", "Bolivia, Plurinational State of", Some("Plurinational State of Bolivia")),
+    ("BR", "BRA", "076", "Brazil", Some("Federative Republic of Brazil")),
+    ("BB", "BRB", "052", "Barbados", None),
+    ("BN", "BRN", "096", "Brunei Darussalam", None),
+    ("BT", "BTN", "064", "Bhutan", Some("Kingdom of Bhutan")),
+    ("BV", "BVT", "074", "Bouvet Island", None),
+    ("BW", "BWA", "072", "Botswana", Some("Republic of Botswana")),
+    ("CF", "CAF", "140", "Central African Republic", None),
+    ("CA", "CAN", "124", "Canada", None),
+    ("CC", "CCK", "166", "Cocos (Keeling) Islands", None),
+    ("CH", "CHE", "756", "Switzerland", Some("Swiss Confederation")),
+    ("CL", "CHL", "152", "Chile", Some("Republic of Chile")),
+    ("CN", "CHN", "156", "China", Some("People's Republic of China")),
+    ("CI", "CIV", "384", "Côte d'Ivoire", Some("Republic of Côte d'Ivoire")),
+    ("CM", "CMR", "120", "Cameroon", Some("Republic of Cameroon")),
+    ("CD", "COD", "180", "Congo, The Democratic Republic of the", None),
+    ("CG", "COG", "178", "Congo", Some("Republic of the Congo")),
+    ("CK", "COK", "184", "Cook Islands", None),
+    ("CO", "COL", "170", "Colombia", Some("Republic of Colombia")),
+    ("KM", "COM", "174", "Comoros", Some("Union of the Comoros")),
+    ("CV", "CPV", "132", "Cabo Verde", Some("Republic of Cabo Verde")),
+    ("CR", "CRI", "188", "Costa Rica", Some("Republic of Costa Rica")),
+    ("CU", "CUB", "192", "Cuba", Some("Republic of Cuba")),
+    ("CW", "CUW", "531", "Curaçao", None),
+    ("CX", "CXR", "162", "Christmas Island", None),
+    ("KY", "CYM", "136", "Cayman Islands", None),
+    ("CY", "CYP", "196", "Cyprus", Some("Republic of Cyprus")),
+    ("CZ", "CZE", "203", "Czechia", Some("Czech Republic")),
+    ("DE", "DEU", "276", "Germany", Some("Federal Republic of Germany")),
+    ("DJ", "DJI", "262", "Djibouti", Some("Republic of Djibouti")),
+    ("DM", "DMA", "212", "Dominica", Some("Commonwealth of Dominica")),
+    ("DK", "DNK", "208", "Denmark", Some("Kingdom of Denmark")),
+    ("DO", "DOM", "214", "Dominican Republic", None),
+    ("DZ", "DZA", "012", "Algeria", Some("People's Democratic Republic of Algeria")),
+    ("EC", "ECU", "218", "Ecuador", Some("Republic of Ecuador")),
+    ("EG", "EGY", "818", "Egypt", Some("Arab Republic of Egypt")),
+    ("ER", "ERI", "232", "Eritrea", Some("the State of Eritrea")),
+    ("EH", "ESH", "732", "Western Sahara", None),
+    ("ES", "ESP", "724", "Spain", Some("Kingdom of Spain")),
+    ("EE", "EST", "233", "Estonia", Some("Republic of Estonia")),
+    ("ET", "ETH", "231", "Ethiopia", Some("Federal Democratic Republic of Ethiopia")),
+    ("FI", "FIN", "246", "Finland", Some("Republic of Finland")),
+    ("FJ", "FJI", "242", "Fiji", Some("Republic of Fiji")),
+    ("FK", "FLK", "238", "Falkland Islands (Malvinas)", None),
+    ("FR", "FRA", "250", "France", Some("French Republic")),
+    ("FO", "FRO", "234", "Faroe Islands", None),
+    ("FM", "FSM", "583", "Micronesia, Federated States of", Some("Federated States of Micronesia")),
+    ("GA", "GAB", "266", "Gabon", Some("Gabonese Republic")),
+    ("GB", "GBR", "826", "United Kingdom", Some("United Kingdom of Great Britain and Northern Ireland")),
+    ("GE", "GEO", "268", "Georgia", None),
+    ("GG", "GGY", "831", "Guernsey", None),
+    ("GH", "GHA", "288", "Ghana", Some("Republic of Ghana")),
+    ("GI", "GIB", "292", "Gibraltar", None),
+    ("GN", "GIN", "324", "Guinea", Some("Republic of Guinea")),
+    ("GP", "GLP", "312", "Guadeloupe", None),
+    ("GM", "GMB", "270", "Gambia", Some("Republic of the Gambia")),
+    ("GW", "GNB", "624", "Guinea-Bissau", Some("Republic of Guinea-Bissau")),
+    ("GQ", "GNQ", "226", "Equatorial Guinea", Some("Republic of Equatorial Guinea")),
+    ("GR", "GRC", "300", "Greece", Some("Hellenic Republic")),
+    ("GD", "GRD", "308", "Grenada", None),
+    ("GL", "GRL", "304", "Greenland", None),
+    ("GT", "GTM", "320", "Guatemala", Some("Republic of Guatemala")),
+    ("GF", "GUF", "254", "French Guiana", None),
+    ("GU", "GUM", "316", "Guam", None),
+    ("GY", "GUY", "328", "Guyana", Some("Republic of Guyana")),
+    ("HK", "HKG", "344", "Hong Kong", Some("Hong Kong Special Administrative Region of China")),
+    ("HM", "HMD", "334", "Heard Island and McDonald Islands", None),
+    ("HN", "HND", "340", "Honduras", Some("Republic of Honduras")),
+    ("HR", "HRV", "191", "Croatia", Some("Republic of Croatia")),
+    ("HT", "HTI", "332", "Haiti", Some("Republic of Haiti")),
+    ("HU", "HUN", "348", "Hungary", None),
+    ("ID", "IDN", "360", "Indonesia", Some("Republic of Indonesia")),
+    ("IM", "IMN", "833", "Isle of Man", None),
+    ("IN", "IND", "356", "India", Some("Republic of India")),
+    ("IO", "IOT", "086", "British Indian Ocean Territory", None),
+    ("IE", "IRL", "372", "Ireland", None),
+    ("IR", "IRN", "364", "Iran, Islamic Republic of", Some("Islamic Republic of Iran")),
+    ("IQ", "IRQ", "368", "Iraq", Some("Republic of Iraq")),
+    ("IS", "ISL", "352", "Iceland", Some("Republic of Iceland")),
+    ("IL", "ISR", "376", "Israel", Some("State of Israel")),
+    ("IT", "ITA", "380", "Italy", Some("Italian Republic")),
+    ("JM", "JAM", "388", "Jamaica", None),
+    ("JE", "JEY", "832", "Jersey", None),
+    ("JO", "JOR", "400", "Jordan", Some("Hashemite Kingdom of Jordan")),
+    ("JP", "JPN", "392", "Japan", None),
+    ("KZ", "KAZ", "398", "Kazakhstan", Some("Republic of Kazakhstan")),
+    ("KE", "KEN", "404", "Kenya", Some("Republic of Kenya")),
+    ("KG", "KGZ", "417", "Kyrgyzstan", Some("Kyrgyz Republic")),
+    ("KH", "KHM", "116", "Cambodia", Some("Kingdom of Cambodia")),
+    ("KI", "KIR", "296", "Kiribati", Some("Republic of Kiribati")),
+    ("KN", "KNA", "659", "Saint Kitts and Nevis", None),
+    ("KR", "KOR", "410", "Korea, Republic of", None),
+    ("KW", "KWT", "414", "Kuwait", Some("State of Kuwait")),
+    ("LA", "LAO", "418", "Lao People's Democratic Republic", None),
+    ("LB", "LBN", "422", "Lebanon", Some("Lebanese Republic")),
+    ("LR", "LBR", "430", "Liberia", Some("Republic of Liberia")),
+    ("LY", "LBY", "434", "Libya", None),
+    ("LC", "LCA", "662", "Saint Lucia", None),
+    ("LI", "LIE", "438", "Liechtenstein", Some("Principality of Liechtenstein")),
+    ("LK", "LKA", "144", "Sri Lanka", Some("Democratic Socialist Republic of Sri Lanka")),
+    ("LS", "LSO", "426", "Lesotho", Some("Kingdom of Lesotho")),
+    ("LT", "LTU", "440", "Lithuania", Some("Republic of Lithuania")),
+    ("LU", "LUX", "442", "Luxembourg", Some("Grand Duchy of Luxembourg")),
+    ("LV", "LVA", "428", "Latvia", Some("Republic of Latvia")),
+    ("MO", "MAC", "446", "Macao", Some("Macao Special Administrative Region of China")),
+    ("MF", "MAF", "663", "Saint Martin (French part)", None),
+    ("MA", "MAR", "504", "Morocco", Some("Kingdom of Morocco")),
+    ("MC", "MCO", "492", "Monaco", Some("Principality of Monaco")),
+    ("MD", "MDA", "498", "Moldova, Republic of", Some("Republic of Moldova")),
+    ("MG", "MDG", "450", "Madagascar", Some("Republic of Madagascar")),
+    ("MV", "MDV", "462", "Maldives", Some("Republic of Maldives")),
+    ("MX", "MEX", "484", "Mexico", Some("United Mexican States")),
+    ("MH", "MHL", "584", "Marshall Islands", Some("Republic of the Marshall Islands")),
+    ("MK", "MKD", "807", "North Macedonia", Some("Republic of North Macedonia")),
+    ("ML", "MLI", "466", "Mali", Some("Republic of Mali")),
+    ("MT", "MLT", "470", "Malta", Some("Republic of Malta")),
+    ("MM", "MMR", "104", "Myanmar", Some("Republic of Myanmar")),
+    ("ME", "MNE", "499", "Montenegro", None),
+    ("MN", "MNG", "496", "Mongolia", None),
+    ("MP", "MNP", "580", "Northern Mariana Islands", Some("Commonwealth of the Northern Mariana Islands")),
+    ("MZ", "MOZ", "508", "Mozambique", Some("Republic of Mozambique")),
+    ("MR", "MRT", "478", "Mauritania", Some("Islamic Republic of Mauritania")),
+    ("MS", "MSR", "500", "Montserrat", None),
+    ("MQ", "MTQ", "474", "Martinique", None),
+    ("MU", "MUS", "480", "Mauritius", Some("Republic of Mauritius")),
+    ("MW", "MWI", "454", "Malawi", Some("Republic of Malawi")),
+    ("MY", "MYS", "458", "Malaysia", None),
+    ("YT", "MYT", "175", "Mayotte", None),
+    ("NA", "NAM", "516", "Namibia", Some("Republic of Namibia")),
+    ("NC", "NCL", "540", "New Caledonia", None),
+    ("NE", "NER", "562", "Niger", Some("Republic of the Niger")),
+    ("NF", "NFK", "574", "Norfolk Island", None),
+    ("NG", "NGA", "566", "Nigeria", Some("Federal Republic of Nigeria")),
+    ("NI", "NIC", "558", "Nicaragua", Some("Republic of Nicaragua")),
+    ("NU", "NIU", "570", "Niue", None),
+    ("NL", "NLD", "528", "Netherlands", Some("Kingdom of the Netherlands")),
+    ("NO", "NOR", "578", "Norway", Some("Kingdom of Norway")),
+    ("NP", "NPL", "524", "Nepal", Some("Federal Democratic Republic of Nepal")),
+    ("NR", "NRU", "520", "Nauru", Some("Republic of Nauru")),
+    ("NZ", "NZL", "554", "New Zealand", None),
+    ("OM", "OMN", "512", "Oman", Some("Sultanate of Oman")),
+    ("PK", "PAK", "586", "Pakistan", Some("Islamic Republic of Pakistan")),
+    ("PA", "PAN", "591", "Panama", Some("Republic of Panama")),
+    ("PN", "PCN", "612", "Pitcairn", None),
+    ("PE", "PER", "604", "Peru", Some("Republic of Peru")),
+    ("PH", "PHL", "608", "Philippines", Some("Republic of the Philippines")),
+    ("PW", "PLW", "585", "Palau", Some("Republic of Palau")),
+    ("PG", "PNG", "598", "Papua New Guinea", Some("Independent State of Papua New Guinea")),
+    ("PL", "POL", "616", "Poland", Some("Republic of Poland")),
+    ("PR", "PRI", "630", "Puerto Rico", None),
+    ("KP", "PRK", "408", "Korea, Democratic People's Republic of", Some("Democratic People's Republic of Korea")),
+    ("PT", "PRT", "620", "Portugal", Some("Portuguese Republic")),
+    ("PY", "PRY", "600", "Paraguay", Some("Republic of Paraguay")),
+    ("PS", "PSE", "275", "Palestine, State of", Some("the State of Palestine")),
+    ("PF", "PYF", "258", "French Polynesia", None),
+    ("QA", "QAT", "634", "Qatar", Some("State of Qatar")),
+    ("RE", "REU", "638", "Réunion", None),
+    ("RO", "ROU", "642", "Romania", None),
+    ("RU", "RUS", "643", "Russian Federation", None),
+    ("RW", "RWA", "646", "Rwanda", Some("Rwandese Republic")),
+    ("SA", "SAU", "682", "Saudi Arabia", Some("Kingdom of Saudi Arabia")),
+    ("SD", "SDN", "729", "Sudan", Some("Republic of the Sudan")),
+    ("SN", "SEN", "686", "Senegal", Some("Republic of Senegal")),
+    ("SG", "SGP", "702", "Singapore", Some("Republic of Singapore")),
+    ("GS", "SGS", "239", "South Georgia and the South Sandwich Islands", None),
+    ("SH", "SHN", "654", "Saint Helena, Ascension and Tristan da Cunha", None),
+    ("SJ", "SJM", "744", "Svalbard and Jan Mayen", None),
+    ("SB", "SLB", "090", "Solomon Islands", None),
+    ("SL", "SLE", "694", "Sierra Leone", Some("Republic of Sierra Leone")),
+    ("SV", "SLV", "222", "El Salvador", Some("Republic of El Salvador")),
+    ("SM", "SMR", "674", "San Marino", Some("Republic of San Marino")),
+    ("SO", "SOM", "706", "Somalia", Some("Federal Republic of Somalia")),
+    ("PM", "SPM", "666", "Saint Pierre and Miquelon", None),
+    ("RS", "SRB", "688", "Serbia", Some("Republic of Serbia")),
+    ("SS", "SSD", "728", "South Sudan", Some("Republic of South Sudan")),
+    ("ST", "STP", "678", "Sao Tome and Principe", Some("Democratic Republic of Sao Tome and Principe")),
+    ("SR", "SUR", "740", "Suriname", Some("Republic of Suriname")),
+    ("SK", "SVK", "703", "Slovakia", Some("Slovak Republic")),
+    ("SI", "SVN", "705", "Slovenia", Some("Republic of Slovenia")),
+    ("SE", "SWE", "752", "Sweden", Some("Kingdom of Sweden")),
+    ("SZ", "SWZ", "748", "Eswatini", Some("Kingdom of Eswatini")),
+    ("SX", "SXM", "534", "Sint Maarten (Dutch part)", None),
+    ("SC", "SYC", "690", "Seychelles", Some("Republic of Seychelles")),
+    ("SY", "SYR", "760", "Syrian Arab Republic", None),
+    ("TC", "TCA", "796", "Turks and Caicos Islands", None),
+    ("TD", "TCD", "148", "Chad", Some("Republic of Chad")),
+    ("TG", "TGO", "768", "Togo", Some("Togolese Republic")),
+    ("TH", "THA", "764", "Thailand", Some("Kingdom of Thailand")),
+    ("TJ", "TJK", "762", "Tajikistan", Some("Republic of Tajikistan")),
+    ("TK", "TKL", "772", "Tokelau", None),
+    ("TM", "TKM", "795", "Turkmenistan", None),
+    ("TL", "TLS", "626", "Timor-Leste", Some("Democratic Republic of Timor-Leste")),
+    ("TO", "TON", "776", "Tonga", Some("Kingdom of Tonga")),
+    ("TT", "TTO", "780", "Trinidad and Tobago", Some("Republic of Trinidad and Tobago")),
+    ("TN", "TUN", "788", "Tunisia", Some("Republic of Tunisia")),
+    ("TR", "TUR", "792", "Türkiye", Some("Republic of Türkiye")),
+    ("TV", "TUV", "798", "Tuvalu", None),
+    ("TW", "TWN", "158", "Taiwan, Province of China", None),
+    ("TZ", "TZA", "834", "Tanzania, United Republic of", Some("United Republic of Tanzania")),
+    ("UG", "UGA", "800", "Uganda", Some("Republic of Uganda")),
+    ("UA", "UKR", "804", "Ukraine", None),
+    ("UM", "UMI", "581", "United States Minor Outlying Islands", None),
+    ("UY", "URY", "858", "Uruguay", Some("Eastern Republic of Uruguay")),
+    ("US", "USA", "840", "United States", Some("United States of America")),
+    ("UZ", "UZB", "860", "Uzbekistan", Some("Republic of Uzbekistan")),
+    ("VA", "VAT", "336", "Holy See (Vatican City State)", None),
+    ("VC", "VCT", "670", "Saint Vincent and the Grenadines", None),
+    ("VE", "VEN", "862", "Venezuela, Bolivarian Republic of", Some("Bolivarian Republic of Venezuela")),
+    ("VG", "VGB", "092", "Virgin Islands, British", Some("British Virgin Islands")),
+    ("VI", "VIR", "850", "Virgin Islands, U.S.", Some("Virgin Islands of the United States")),
+    ("VN", "VNM", "704", "Viet Nam", Some("Socialist Republic of Viet Nam")),
+    ("VU", "VUT", "548", "Vanuatu", Some("Republic of Vanuatu")),
+    ("WF", "WLF", "876", "Wallis and Futuna", None),
+    ("WS", "WSM", "882", "Samoa", Some("Independent State of Samoa")),
+    ("YE", "YEM", "887", "Yemen", Some("Republic of Yemen")),
+    ("ZA", "ZAF", "710", "South Africa", Some("Republic of South Africa")),
+    ("ZM", "ZMB", "894", "Zambia", Some("Republic of Zambia")),
+    ("ZW", "ZWE", "716", "Zimbabwe", Some("Republic of Zimbabwe")),
+];
+
+// countries keyed by their two letter code, the region subtag of language tags
+pub fn lookup_countries() -> Countries {
+    let mut countries = ISO_3166_1.iter()
+        .map(|(alpha_2, alpha_3, numeric, name, official_name)| {
+            let description = official_name.map(Description::from).unwrap_or_default();
+
+            let codes = [alpha_2, alpha_3, numeric].into_iter().map(ToString::to_string).collect();
+
+            (alpha_2.to_string(), Country { name: name.to_string(), description, codes })
+        })
+        .collect::<Countries>();
+
+    countries.sort_keys();
+
+    countries
+}
\ No newline at end of file
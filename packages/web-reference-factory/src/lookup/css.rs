@@ -0,0 +1,46 @@
+use web_reference::prelude::*;
+
+// neither w3schools nor the specs say which properties replace an obsolete tag
+const TAG_CSS_ALTERNATIVES: &[(&str, &[&str])] = &[
+    ("basefont", &["font-family", "font-size", "color"]),
+    ("big", &["font-size"]),
+    ("center", &["text-align"]),
+    ("font", &["font-family", "font-size", "color"]),
+    ("tt", &["font-family"]),
+];
+
+// obsolete presentational attributes and the property that replaces each, css properties are
+// only built by `reffy`
+#[cfg(feature = "reffy")]
+const PRESENTATIONAL_ATTRIBUTES: &[(&str, &str)] = &[
+    ("align", "text-align"),
+    ("background", "background-image"),
+    ("bgcolor", "background-color"),
+    ("border", "border-width"),
+    ("cellpadding", "padding"),
+    ("cellspacing", "border-spacing"),
+    ("clear", "clear"),
+    ("color", "color"),
+    ("face", "font-family"),
+    ("hspace", "margin"),
+    ("nowrap", "white-space"),
+    ("valign", "vertical-align"),
+    ("vspace", "margin"),
+];
+
+// properties that replace an obsolete tag, none for tags without known alternatives
+pub fn css_alternatives(tag: &str) -> AlternativeCssProperties {
+    TAG_CSS_ALTERNATIVES.iter()
+        .find(|(obsolete, _)| *obsolete == tag)
+        .map(|(_, properties)| properties.iter().map(ToString::to_string).collect())
+        .unwrap_or_default()
+}
+
+// presentational attributes a property replaces
+#[cfg(feature = "reffy")]
+pub fn presentational_attributes(property: &str) -> HasAttributes {
+    PRESENTATIONAL_ATTRIBUTES.iter()
+        .filter(|(_, replacement)| *replacement == property)
+        .map(|(attribute, _)| attribute.to_string())
+        .collect()
+}
@@ -0,0 +1,514 @@
+use web_reference::prelude::*;
+
+// the ISO 639-2 languages of the debian iso-codes 4.15.0 package, without the reserved ranges, i.e. `qaa-qtz`
+// for local use, as their name, two letter code if any, three letter code and the older, english based, code
+// of a few languages, i.e. `ger` for `deu`
+static ISO_639_2: [(&str, Option<&str>, &str, Option<&str>); 486] = [
+    ("Afar", Some("aa"), "aar", None),
+    ("Abkhazian", Some("ab"), "abk", None),
+    ("Achinese", None, "ace", None),
+    ("Acoli", None, "ach", None),
+    ("Adangme", None, "ada", None),
+    ("Adyghe; Adygei", None, "ady", None),
+    ("Afro-Asiatic languages", None, "afa", None),
+    ("Afrihili", None, "afh", None),
+    ("Afrikaans", Some("af"), "afr", None),
+    ("Ainu", None, "ain", None),
+    ("Akan", Some("ak"), "aka", None),
+    ("Akkadian", None, "akk", None),
+    ("Aleut", None, "ale", None),
+    ("Algonquian languages", None, "alg", None),
+    ("Southern Altai", None, "alt", None),
+    ("Amharic", Some("am"), "amh", None),
+    ("English, Old (ca. 450-1100)", None, "ang", None),
+    ("Angika", None, "anp", None),
+    ("Apache languages", None, "apa", None),
+    ("Arabic", Some("ar"), "ara", None),
+    ("Official Aramaic (700-300 BCE); Imperial Aramaic (700-300 BCE)", None, "arc", None),
+    ("Aragonese", Some("an"), "arg", None),
+    ("Mapudungun; Mapuche", None, "arn", None),
+    ("Arapaho", None, "arp", None),
+    ("Artificial languages", None, "art", None),
+    ("Arawak", None, "arw", None),
+    ("Assamese", Some("as"), "asm", None),
+    ("Asturian; Bable; Leonese; Asturleonese", None, "ast", None),
+    ("Athapascan languages", None, "ath", None),
+    ("Australian languages", None, "aus", None),
+    ("Avaric", Some("av"), "ava", None),
+    ("Avestan", Some("ae"), "ave", None),
+    ("Awadhi", None, "awa", None),
+    ("Aymara", Some("ay"), "aym", None),
+    ("Azerbaijani", Some("az"), "aze", None),
+    ("Banda languages", None, "bad", None),
+    ("Bamileke languages", None, "bai", None),
+    ("Bashkir", Some("ba"), "bak", None),
+    ("Baluchi", None, "bal", None),
+    ("Bambara", Some("bm"), "bam", None),
+    ("Balinese", None, "ban", None),
+    ("Basa", None, "bas", None),
+    ("Baltic languages", None, "bat", None),
+    ("Beja; Bedawiyet", None, "bej", None),
+    ("Belarusian", Some("be"), "bel", None),
+    ("Bemba", None, "bem", None),
+    ("Bengali", Some("bn"), "ben", None),
+    ("Berber languages", None, "ber", None),
+    ("Bhojpuri", None, "bho", None),
+    ("Bihari languages", Some("bh"), "bih", None),
+    ("Bikol", None, "bik", None),
+    ("Bini; Edo", None, "bin", None),
+    ("Bislama", Some("bi"), "bis", None),
+    ("Siksika", None, "bla", None),
+    ("Bantu (Other)", None, "bnt", None),
+    ("Tibetan", Some("bo"), "bod", Some("tib")),
+    ("Bosnian", Some("bs"), "bos", None),
+    ("Braj", None, "bra", None),
+    ("Breton", Some("br"), "bre", None),
+    ("Batak languages", None, "btk", None),
+    ("Buriat", None, "bua", None),
+    ("Buginese", None, "bug", None),
+    ("Bulgarian", Some("bg"), "bul", None),
+    ("Blin; Bilin", None, "byn", None),
+    ("Caddo", None, "cad", None),
+    ("Central American Indian languages", None, "cai", None),
+    ("Galibi Carib", None, "car", None),
+    ("Catalan; Valencian", Some("ca"), "cat", None),
+    ("Caucasian languages", None, "cau", None),
+    ("Cebuano", None, "ceb", None),
+    ("Celtic languages", None, "cel", None),
+    ("Czech", Some("cs"), "ces", Some("cze")),
+    ("Chamorro", Some("ch"), "cha", None),
+    ("Chibcha", None, "chb", None),
+    ("Chechen", Some("ce"), "che", None),
+    ("Chagatai", None, "chg", None),
+    ("Chuukese", None, "chk", None),
+    ("Mari", None, "chm", None),
+    ("Chinook jargon", None, "chn", None),
+    ("Choctaw", None, "cho", None),
+    ("Chipewyan; Dene Suline", None, "chp", None),
+    ("Cherokee", None, "chr", None),
+    ("Church Slavic; Old Slavonic; Church Slavonic; Old Bulgarian; Old Church Slavonic", Some("cu"), "chu", None),
+    ("Chuvash", Some("cv"), "chv", None),
+    ("Cheyenne", None, "chy", None),
+    ("Chamic languages", None, "cmc", None),
+    ("Montenegrin", None, "cnr", None),
+    ("Coptic", None, "cop", None),
+    ("Cornish", Some("kw"), "cor", None),
+    ("Corsican", Some("co"), "cos", None),
+    ("Creoles and pidgins, English based", None, "cpe", None),
+    ("Creoles and pidgins, French-based", None, "cpf", None),
+    ("Creoles and pidgins, Portuguese-based", None, "cpp", None),
+    ("Cree", Some("cr"), "cre", None),
+    ("Crimean Tatar; Crimean Turkish", None, "crh", None),
+    ("Creoles and pidgins", None, "crp", None),
+    ("Kashubian", None, "csb", None),
+    ("Cushitic languages", None, "cus", None),
+    ("Welsh", Some("cy"), "cym", Some("wel")),
+    ("Dakota", None, "dak", None),
+    ("Danish", Some("da"), "dan", None),
+    ("Dargwa", None, "dar", None),
+    ("Land Dayak languages", None, "day", None),
+    ("Delaware", None, "del", None),
+    ("Slave (Athapascan)", None, "den", None),
+    ("German", Some("de"), "deu", Some("ger")),
+    ("Dogrib", None, "dgr", None),
+    ("Dinka", None, "din", None),
+    ("Divehi; Dhivehi; Maldivian", Some("dv"), "div", None),
+    ("Dogri", None, "doi", None),
+    ("Dravidian languages", None, "dra", None),
+    ("Lower Sorbian", None, "dsb", None),
+    ("Duala", None, "dua", None),
+    ("Dutch, Middle (ca. 1050-1350)", None, "dum", None),
+    ("Dyula", None, "dyu", None),
+    ("Dzongkha", Some("dz"), "dzo", None),
+    ("Efik", None, "efi", None),
+    ("Egyptian (Ancient)", None, "egy", None),
+    ("Ekajuk", None, "eka", None),
+    ("Greek, Modern (1453-)", Some("el"), "ell", Some("gre")),
+    ("Elamite", None, "elx", None),
+    ("English", Some("en"), "eng", None),
+    ("English, Middle (1100-1500)", None, "enm", None),
+    ("Esperanto", Some("eo"), "epo", None),
+    ("Estonian", Some("et"), "est", None),
+    ("Basque", Some("eu"), "eus", Some("baq")),
+    ("Ewe", Some("ee"), "ewe", None),
+    ("Ewondo", None, "ewo", None),
+    ("Fang", None, "fan", None),
+    ("Faroese", Some("fo"), "fao", None),
+    ("Persian", Some("fa"), "fas", Some("per")),
+    ("Fanti", None, "fat", None),
+    ("Fijian", Some("fj"), "fij", None),
+    ("Filipino; Pilipino", None, "fil", None),
+    ("Finnish", Some("fi"), "fin", None),
+    ("Finno-Ugrian languages", None, "fiu", None),
+    ("Fon", None, "fon", None),
+    ("French", Some("fr"), "fra", Some("fre")),
+    ("French, Middle (ca. 1400-1600)", None, "frm", None),
+    ("French, Old (842-ca. 1400)", None, "fro", None),
+    ("Northern Frisian", None, "frr", None),
+    ("Eastern Frisian", None, "frs", None),
+    ("Western Frisian", Some("fy"), "fry", None),
+    ("Fulah", Some("ff"), "ful", None),
+    ("Friulian", None, "fur", None),
+    ("Ga", None, "gaa", None),
+    ("Gayo", None, "gay", None),
+    ("Gbaya", None, "gba", None),
+    ("Germanic languages", None, "gem", None),
+    ("Geez", None, "gez", None),
+    ("Gilbertese", None, "gil", None),
+    ("Gaelic; Scottish Gaelic", Some("gd"), "gla", None),
+    ("Irish", Some("ga"), "gle", None),
+    ("Galician", Some("gl"), "glg", None),
+    ("Manx", Some("gv"), "glv", None),
+    ("German, Middle High (ca. 1050-1500)", None, "gmh", None),
+    ("German, Old High (ca. 750-1050)", None, "goh", None),
+    ("Gondi", None, "gon", None),
+    ("Gorontalo", None, "gor", None),
+    ("Gothic", None, "got", None),
+    ("Grebo", None, "grb", None),
+    ("Greek, Ancient (to 1453)", None, "grc", None),
+    ("Guarani", Some("gn"), "grn", None),
+    ("Swiss German; Alemannic; Alsatian", None, "gsw", None),
+    ("Gujarati", Some("gu"), "guj", None),
+    ("Gwich'in", None, "gwi", None),
+    ("Haida", None, "hai", None),
+    ("Haitian; Haitian Creole", Some("ht"), "hat", None),
+    ("Hausa", Some("ha"), "hau", None),
+    ("Hawaiian", None, "haw", None),
+    ("Hebrew", Some("he"), "heb", None),
+    ("Herero", Some("hz"), "her", None),
+    ("Hiligaynon", None, "hil", None),
+    ("Himachali languages; Western Pahari languages", None, "him", None),
+    ("Hindi", Some("hi"), "hin", None),
+    ("Hittite", None, "hit", None),
+    ("Hmong; Mong", None, "hmn", None),
+    ("Hiri Motu", Some("ho"), "hmo", None),
+    ("Croatian", Some("hr"), "hrv", None),
+    ("Upper Sorbian", None, "hsb", None),
+    ("Hungarian", Some("hu"), "hun", None),
+    ("Hupa", None, "hup", None),
+    ("Armenian", Some("hy"), "hye", Some("arm")),
+    ("Iban", None, "iba", None),
+    ("Igbo", Some("ig"), "ibo", None),
+    ("Ido", Some("io"), "ido", None),
+    ("Sichuan Yi; Nuosu", Some("ii"), "iii", None),
+    ("Ijo languages", None, "ijo", None),
+    ("Inuktitut", Some("iu"), "iku", None),
+    ("Interlingue; Occidental", Some("ie"), "ile", None),
+    ("Iloko", None, "ilo", None),
+    ("Interlingua (International Auxiliary Language Association)", Some("ia"), "ina", None),
+    ("Indic languages", None, "inc", None),
+    ("Indonesian", Some("id"), "ind", None),
+    ("Indo-European languages", None, "ine", None),
+    ("Ingush", None, "inh", None),
+    ("Inupiaq", Some("ik"), "ipk", None),
+    ("Iranian languages", None, "ira", None),
+    ("Iroquoian languages", None, "iro", None),
+    ("Icelandic", Some("is"), "isl", Some("ice")),
+    ("Italian", Some("it"), "ita", None),
+    ("Javanese", Some("jv"), "jav", None),
+    ("Lojban", None, "jbo", None),
+    ("Japanese", Some("ja"), "jpn", None),
+    ("Judeo-Persian", None, "jpr", None),
+    ("Judeo-Arabic", None, "jrb", None),
+    ("Kara-Kalpak", None, "kaa", None),
+    ("Kabyle", None, "kab", None),
+    ("Kachin; Jingpho", None, "kac", None),
+    ("Kalaallisut; Greenlandic", Some("kl"), "kal", None),
+    ("Kamba", None, "kam", None),
+    ("Kannada", Some("kn"), "kan", None),
+    ("Karen languages", None, "kar", None),
+    ("Kashmiri", Some("ks"), "kas", None),
+    ("Georgian", Some("ka"), "kat", Some("geo")),
+    ("Kanuri", Some("kr"), "kau", None),
+    ("Kawi", None, "kaw", None),
+    ("Kazakh", Some("kk"), "kaz", None),
+    ("Kabardian", None, "kbd", None),
+    ("Khasi", None, "kha", None),
+    ("Khoisan languages", None, "khi", None),
+    ("Central Khmer", Some("km"), "khm", None),
+    ("Khotanese; Sakan", None, "kho", None),
+    ("Kikuyu; Gikuyu", Some("ki"), "kik", None),
+    ("Kinyarwanda", Some("rw"), "kin", None),
+    ("Kirghiz; Kyrgyz", Some("ky"), "kir", None),
+    ("Kimbundu", None, "kmb", None),
+    ("Konkani", None, "kok", None),
+    ("Komi", Some("kv"), "kom", None),
+    ("Kongo", Some("kg"), "kon", None),
+    ("Korean", Some("ko"), "kor", None),
+    ("Kosraean", None, "kos", None),
+    ("Kpelle", None, "kpe", None),
+    ("Karachay-Balkar", None, "krc", None),
+    ("Karelian", None, "krl", None),
+    ("Kru languages", None, "kro", None),
+    ("Kurukh", None, "kru", None),
+    ("Kuanyama; Kwanyama", Some("kj"), "kua", None),
+    ("Kumyk", None, "kum", None),
+    ("Kurdish", Some("ku"), "kur", None),
+    ("Kutenai", None, "kut", None),
+    ("Ladino", None, "lad", None),
+    ("Lahnda", None, "lah", None),
+    ("Lamba", None, "lam", None),
+    ("Lao", Some("lo"), "lao", None),
+    ("Latin", Some("la"), "lat", None),
+    ("Latvian", Some("lv"), "lav", None),
+    ("Lezghian", None, "lez", None),
+    ("Limburgan; Limburger; Limburgish", Some("li"), "lim", None),
+    ("Lingala", Some("ln"), "lin", None),
+    ("Lithuanian", Some("lt"), "lit", None),
+    ("Mongo", None, "lol", None),
+    ("Lozi", None, "loz", None),
+    ("Luxembourgish; Letzeburgesch", Some("lb"), "ltz", None),
+    ("Luba-Lulua", None, "lua", None),
+    ("Luba-Katanga", Some("lu"), "lub", None),
+    ("Ganda", Some("lg"), "lug", None),
+    ("Luiseno", None, "lui", None),
+    ("Lunda", None, "lun", None),
+    ("Luo (Kenya and Tanzania)", None, "luo", None),
+    ("Lushai", None, "lus", None),
+    ("Madurese", None, "mad", None),
+    ("Magahi", None, "mag", None),
+    ("Marshallese", Some("mh"), "mah", None),
+    ("Maithili", None, "mai", None),
+    ("Makasar", None, "mak", None),
+    ("Malayalam", Some("ml"), "mal", None),
+    ("Mandingo", None, "man", None),
+    ("Austronesian languages", None, "map", None),
+    ("Marathi", Some("mr"), "mar", None),
+    ("Masai", None, "mas", None),
+    ("Moksha", None, "mdf", None),
+    ("Mandar", None, "mdr", None),
+    ("Mende", None, "men", None),
+    ("Irish, Middle (900-1200)", None, "mga", None),
+    ("Mi'kmaq; Micmac", None, "mic", None),
+    ("Minangkabau", None, "min", None),
+    ("Uncoded languages", None, "mis", None),
+    ("Macedonian", Some("mk"), "mkd", Some("mac")),
+    ("Mon-Khmer languages", None, "mkh", None),
+    ("Malagasy", Some("mg"), "mlg", None),
+    ("Maltese", Some("mt"), "mlt", None),
+    ("Manchu", None, "mnc", None),
+    ("Manipuri", None, "mni", None),
+    ("Manobo languages", None, "mno", None),
+    ("Mohawk", None, "moh", None),
+    ("Mongolian", Some("mn"), "mon", None),
+    ("Mossi", None, "mos", None),
+    ("Maori", Some("mi"), "mri", Some("mao")),
+    ("Malay", Some("ms"), "msa", Some("may")),
+    ("Multiple languages", None, "mul", None),
+    ("Munda languages", None, "mun", None),
+    ("Creek", None, "mus", None),
+    ("Mirandese", None, "mwl", None),
+    ("Marwari", None, "mwr", None),
+    ("Burmese", Some("my"), "mya", Some("bur")),
+    ("Mayan languages", None, "myn", None),
+    ("Erzya", None, "myv", None),
+    ("Nahuatl languages", None, "nah", None),
+    ("North American Indian languages", None, "nai", None),
+    ("Neapolitan", None, "nap", None),
+    ("Nauru", Some("na"), "nau", None),
+    ("Navajo; Navaho", Some("nv"), "nav", None),
+    ("Ndebele, South; South Ndebele", Some("nr"), "nbl", None),
+    ("Ndebele, North; North Ndebele", Some("nd"), "nde", None),
+    ("Ndonga", Some("ng"), "ndo", None),
+    ("Low German; Low Saxon; German, Low; Saxon, Low", None, "nds", None),
+    ("Nepali", Some("ne"), "nep", None),
+    ("Nepal Bhasa; Newari", None, "new", None),
+    ("Nias", None, "nia", None),
+    ("Niger-Kordofanian languages", None, "nic", None),
+    ("Niuean", None, "niu", None),
+    ("Dutch; Flemish", Some("nl"), "nld", Some("dut")),
+    ("Norwegian Nynorsk; Nynorsk, Norwegian", Some("nn"), "nno", None),
+    ("Bokmål, Norwegian; Norwegian Bokmål", Some("nb"), "nob", None),
+    ("Nogai", None, "nog", None),
+    ("Norse, Old", None, "non", None),
+    ("Norwegian", Some("no"), "nor", None),
+    ("N'Ko", None, "nqo", None),
+    ("Pedi; Sepedi; Northern Sotho", None, "nso", None),
+    ("Nubian languages", None, "nub", None),
+    ("Classical Newari; Old Newari; Classical Nepal Bhasa", None, "nwc", None),
+    ("Chichewa; Chewa; Nyanja", Some("ny"), "nya", None),
+    ("Nyamwezi", None, "nym", None),
+    ("Nyankole", None, "nyn", None),
+    ("Nyoro", None, "nyo", None),
+    ("Nzima", None, "nzi", None),
+    ("Occitan (post 1500); Provençal", Some("oc"), "oci", None),
+    ("Ojibwa", Some("oj"), "oji", None),
+    ("Oriya", Some("or"), "ori", None),
+    ("Oromo", Some("om"), "orm", None),
+    ("Osage", None, "osa", None),
+    ("Ossetian; Ossetic", Some("os"), "oss", None),
+    ("Turkish, Ottoman (1500-1928)", None, "ota", None),
+    ("Otomian languages", None, "oto", None),
+    ("Papuan languages", None, "paa", None),
+    ("Pangasinan", None, "pag", None),
+    ("Pahlavi", None, "pal", None),
+    ("Pampanga; Kapampangan", None, "pam", None),
+    ("Panjabi; Punjabi", Some("pa"), "pan", None),
+    ("Papiamento", None, "pap", None),
+    ("Palauan", None, "pau", None),
+    ("Persian, Old (ca. 600-400 B.C.)", None, "peo", None),
+    ("Philippine languages", None, "phi", None),
+    ("Phoenician", None, "phn", None),
+    ("Pali", Some("pi"), "pli", None),
+    ("Polish", Some("pl"), "pol", None),
+    ("Pohnpeian", None, "pon", None),
+    ("Portuguese", Some("pt"), "por", None),
+    ("Prakrit languages", None, "pra", None),
+    ("Provençal, Old (to 1500)", None, "pro", None),
+    ("Pushto; Pashto", Some("ps"), "pus", None),
+    ("Quechua", Some("qu"), "que", None),
+    ("Rajasthani", None, "raj", None),
+    ("Rapanui", None, "rap", None),
+    ("Rarotongan; Cook Islands Maori", None, "rar", None),
+    ("Romance languages", None, "roa", None),
+    ("Romansh", Some("rm"), "roh", None),
+    ("Romany", None, "rom", None),
+    ("Romanian; Moldavian; Moldovan", Some("ro"), "ron", Some("rum")),
+    ("Rundi", Some("rn"), "run", None),
+    ("Aromanian; Arumanian; Macedo-Romanian", None, "rup", None),
+    ("Russian", Some("ru"), "rus", None),
+    ("Sandawe", None, "sad", None),
+    ("Sango", Some("sg"), "sag", None),
+    ("Yakut", None, "sah", None),
+    ("South American Indian (Other)", None, "sai", None),
+    ("Salishan languages", None, "sal", None),
+    ("Samaritan Aramaic", None, "sam", None),
+    ("Sanskrit", Some("sa"), "san", None),
+    ("Sasak", None, "sas", None),
+    ("Santali", None, "sat", None),
+    ("Sicilian", None, "scn", None),
+    ("Scots", None, "sco", None),
+    ("Selkup", None, "sel", None),
+    ("Semitic languages", None, "sem", None),
+    ("Irish, Old (to 900)", None, "sga", None),
+    ("Sign Languages", None, "sgn", None),
+    ("Shan", None, "shn", None),
+    ("Sidamo", None, "sid", None),
+    ("Sinhala; Sinhalese", Some("si"), "sin", None),
+    ("Siouan languages", None, "sio", None),
+    ("Sino-Tibetan languages", None, "sit", None),
+    ("Slavic languages", None, "sla", None),
+    ("Slovak", Some("sk"), "slk", Some("slo")),
+    ("Slovenian", Some("sl"), "slv", None),
+    ("Southern Sami", None, "sma", None),
+    ("Northern Sami", Some("se"), "sme", None),
+    ("Sami languages", None, "smi", None),
+    ("Lule Sami", None, "smj", None),
+    ("Inari Sami", None, "smn", None),
+    ("Samoan", Some("sm"), "smo", None),
+    ("Skolt Sami", None, "sms", None),
+    ("Shona", Some("sn"), "sna", None),
+    ("Sindhi", Some("sd"), "snd", None),
+    ("Soninke", None, "snk", None),
+    ("Sogdian", None, "sog", None),
+    ("Somali", Some("so"), "som", None),
+    ("Songhai languages", None, "son", None),
+    ("Sotho, Southern", Some("st"), "sot", None),
+    ("Spanish; Castilian", Some("es"), "spa", None),
+    ("Albanian", Some("sq"), "sqi", Some("alb")),
+    ("Sardinian", Some("sc"), "srd", None),
+    ("Sranan Tongo", None, "srn", None),
+    ("Serbian", Some("sr"), "srp", None),
+    ("Serer", None, "srr", None),
+    ("Nilo-Saharan languages", None, "ssa", None),
+    ("Swati", Some("ss"), "ssw", None),
+    ("Sukuma", None, "suk", None),
+    ("Sundanese", Some("su"), "sun", None),
+    ("Susu", None, "sus", None),
+    ("Sumerian", None, "sux", None),
+    ("Swahili", Some("sw"), "swa", None),
+    ("Swedish", Some("sv"), "swe", None),
+    ("Classical Syriac", None, "syc", None),
+    ("Syriac", None, "syr", None),
+    ("Tahitian", Some("ty"), "tah", None),
+    ("Tai languages", None, "tai", None),
+    ("Tamil", Some("ta"), "tam", None),
+    ("Tatar", Some("tt"), "tat", None),
+    ("Telugu", Some("te"), "tel", None),
+    ("Timne", None, "tem", None),
+    ("Tereno", None, "ter", None),
+    ("Tetum", None, "tet", None),
+    ("Tajik", Some("tg"), "tgk", None),
+    ("Tagalog", Some("tl"), "tgl", None),
+    ("Thai", Some("th"), "tha", None),
+    ("Tigre", None, "tig", None),
+    ("Tigrinya", Some("ti"), "tir", None),
+    ("Tiv", None, "tiv", None),
+    ("Tokelau", None, "tkl", None),
+    ("Klingon; tlhIngan-Hol", None, "tlh", None),
+    ("Tlingit", None, "tli", None),
+    ("Tamashek", None, "tmh", None),
+    ("Tonga (Nyasa)", None, "tog", None),
+    ("Tonga (Tonga Islands)", Some("to"), "ton", None),
+    ("Tok Pisin", None, "tpi", None),
+    ("Tsimshian", None, "tsi", None),
+    ("Tswana", Some("tn"), "tsn", None),
+    ("Tsonga", Some("ts"), "tso", None),
+    ("Turkmen", Some("tk"), "tuk", None),
+    ("Tumbuka", None, "tum", None),
+    ("Tupi languages", None, "tup", None),
+    ("Turkish", Some("tr"), "tur", None),
+    ("Altaic languages", None, "tut", None),
+    ("Tuvalu", None, "tvl", None),
+    ("Twi", Some("tw"), "twi", None),
+    ("Tuvinian", None, "tyv", None),
+    ("Udmurt", None, "udm", None),
+    ("Ugaritic", None, "uga", None),
+    ("Uighur; Uyghur", Some("ug"), "uig", None),
+    ("Ukrainian", Some("uk"), "ukr", None),
+    ("Umbundu", None, "umb", None),
+    ("Undetermined", None, "und", None),
+    ("Urdu", Some("ur"), "urd", None),
+    ("Uzbek", Some("uz"), "uzb", None),
+    ("Vai", None, "vai", None),
+    ("Venda", Some("ve"), "ven", None),
+    ("Vietnamese", Some("vi"), "vie", None),
+    ("Volapük", Some("vo"), "vol", None),
+    ("Votic", None, "vot", None),
+    ("Wakashan languages", None, "wak", None),
+    ("Walamo", None, "wal", None),
+    ("Waray", None, "war", None),
+    ("Washo", None, "was", None),
+    ("Sorbian languages", None, "wen", None),
+    ("Walloon", Some("wa"), "wln", None),
+    ("Wolof", Some("wo"), "wol", None),
+    ("Kalmyk; Oirat", None, "xal", None),
+    ("Xhosa", Some("xh"), "xho", None),
+    ("Yao", None, "yao", None),
+    ("Yapese", None, "yap", None),
+    ("Yiddish", Some("yi"), "yid", None),
+    ("Yoruba", Some("yo"), "yor", None),
+    ("Yupik languages", None, "ypk", None),
+    ("Zapotec", None, "zap", None),
+    ("Blissymbols; Blissymbolics; Bliss", None, "zbl", None),
+    ("Zenaga", None, "zen", None),
+    ("Standard Moroccan Tamazight", None, "zgh", None),
+    ("Zhuang; Chuang", Some("za"), "zha", None),
+    ("Chinese", Some("zh"), "zho", Some("chi")),
+    ("Zande languages", None, "znd", None),
+    ("Zulu", Some("zu"), "zul", None),
+    ("Zuni", None, "zun", None),
+    ("No linguistic content; Not applicable", None, "zxx", None),
+    ("Zaza; Dimili; Dimli; Kirdki; Kirmanjki; Zazaki", None, "zza", None),
+];
+
+// languages keyed by their two letter code, or three letter code for languages without one
+pub fn lookup_languages() -> Languages {
+    let mut languages = ISO_639_2.iter()
+        .map(|(name, alpha_2, alpha_3, bibliographic)| {
+            let key = alpha_2.unwrap_or(*alpha_3).to_string();
+
+            let codes = alpha_2.iter()
+                .chain(std::iter::once(alpha_3))
+                .chain(bibliographic)
+                .map(ToString::to_string)
+                .collect();
+
+            (key, Language { name: name.to_string(), description: Description::default(), codes })
+        })
+        .collect::<Languages>();
+
+    languages.sort_keys();
+
+    languages
+}
\ No newline at end of file
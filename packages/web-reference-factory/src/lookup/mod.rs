@@ -0,0 +1,18 @@
+pub use crate::lookup::aria::*;
+pub use crate::lookup::content::*;
+pub use crate::lookup::countries::*;
+pub use crate::lookup::css::*;
+pub use crate::lookup::languages::*;
+#[cfg(feature = "reffy")]
+pub use crate::lookup::svg::*;
+pub use crate::lookup::syntax::*;
+
+mod aria;
+mod content;
+mod countries;
+mod css;
+mod languages;
+// svg tags and attributes are only built by `reffy`, `build` keeps those of the previous reference
+#[cfg(feature = "reffy")]
+mod svg;
+mod syntax;
@@ -0,0 +1,30 @@
+// attributes of every svg element the idl does not reflect, and the kind of their values
+pub const SVG_CORE_ATTRIBUTES: [(&str, &str); 6] = [
+    ("autofocus", "Boolean"),
+    ("class", "Text"),
+    ("id", "Id"),
+    ("lang", "LanguageCode"),
+    ("style", "Style"),
+    ("tabindex", "Number"),
+];
+
+// svg 2 presentation attributes, each sets the css property of the same name
+pub const SVG_PRESENTATION_ATTRIBUTES: [&str; 61] = [
+    "alignment-baseline", "baseline-shift", "clip-path", "clip-rule", "color", "color-interpolation",
+    "color-interpolation-filters", "cursor", "direction", "display", "dominant-baseline", "fill",
+    "fill-opacity", "fill-rule", "filter", "flood-color", "flood-opacity", "font-family", "font-size",
+    "font-size-adjust", "font-stretch", "font-style", "font-variant", "font-weight",
+    "glyph-orientation-horizontal", "glyph-orientation-vertical", "image-rendering", "letter-spacing",
+    "lighting-color", "marker-end", "marker-mid", "marker-start", "mask", "mask-type", "opacity", "overflow",
+    "paint-order", "pointer-events", "shape-rendering", "stop-color", "stop-opacity", "stroke",
+    "stroke-dasharray", "stroke-dashoffset", "stroke-linecap", "stroke-linejoin", "stroke-miterlimit",
+    "stroke-opacity", "stroke-width", "text-anchor", "text-decoration", "text-overflow", "text-rendering",
+    "transform", "transform-origin", "unicode-bidi", "vector-effect", "visibility", "white-space",
+    "word-spacing", "writing-mode",
+];
+
+// element specific attributes without an animated idl attribute, i.e. `d` is a css property in svg 2
+pub const SVG_SPECIFIC_ATTRIBUTES: [(&str, &[&str]); 2] = [
+    ("d", &["path"]),
+    ("points", &["polygon", "polyline"]),
+];
@@ -0,0 +1,271 @@
+use web_reference::prelude::*;
+
+use ElementKind::{EscapableRawText, Foreign, RawText, Template, Void};
+
+// the tags that are not normal elements, see the kinds of elements of the html standard, obsolete void tags
+// included
+const ELEMENT_KINDS: &[(&str, ElementKind)] = &[
+    ("area", Void),
+    ("base", Void),
+    ("basefont", Void),
+    ("br", Void),
+    ("col", Void),
+    ("embed", Void),
+    ("frame", Void),
+    ("hr", Void),
+    ("img", Void),
+    ("input", Void),
+    ("link", Void),
+    ("meta", Void),
+    ("param", Void),
+    ("source", Void),
+    ("track", Void),
+    ("wbr", Void),
+    ("template", Template),
+    ("script", RawText),
+    ("style", RawText),
+    ("textarea", EscapableRawText),
+    ("title", EscapableRawText),
+    ("svg", Foreign),
+];
+
+// a row of `START_TAG_OMISSIONS` and `END_TAG_OMISSIONS`, see `OmissionRule` for the meaning of its fields
+#[allow(clippy::struct_excessive_bools)]
+struct Rule {
+    tag: &'static str,
+    description: &'static str,
+    when_empty: bool,
+    at_parent_end: bool,
+    excluded_parents: &'static [&'static str],
+    next_tags: &'static [&'static str],
+    next_element: bool,
+    excluded_next_tags: &'static [&'static str],
+    excluded_next_comment: bool,
+    excluded_next_whitespace: bool,
+}
+
+const NEVER: Rule = Rule {
+    tag: "",
+    description: "",
+    when_empty: false,
+    at_parent_end: false,
+    excluded_parents: &[],
+    next_tags: &[],
+    next_element: false,
+    excluded_next_tags: &[],
+    excluded_next_comment: false,
+    excluded_next_whitespace: false,
+};
+
+// the optional start tags of the html standard, the conditions on a preceding sibling of `<colgroup>` and
+// `<tbody>` are only described
+const START_TAG_OMISSIONS: &[Rule] = &[
+    Rule {
+        tag: "html",
+        description: "If the first thing inside the html element is not a comment",
+        excluded_next_comment: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "head",
+        description: "If the element is empty, or if the first thing inside the head element is an element",
+        when_empty: true,
+        next_element: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "body",
+        description: "If the element is empty, or if the first thing inside the body element is not ASCII whitespace or a comment, except if the first thing inside the body element is a meta, noscript, link, script, style, or template element",
+        when_empty: true,
+        excluded_next_tags: &["link", "meta", "noscript", "script", "style", "template"],
+        excluded_next_comment: true,
+        excluded_next_whitespace: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "colgroup",
+        description: "If the first thing inside the colgroup element is a col element, and if the element is not immediately preceded by another colgroup element whose end tag has been omitted",
+        next_tags: &["col"],
+        ..NEVER
+    },
+    Rule {
+        tag: "tbody",
+        description: "If the first thing inside the tbody element is a tr element, and if the element is not immediately preceded by a tbody, thead, or tfoot element whose end tag has been omitted",
+        next_tags: &["tr"],
+        ..NEVER
+    },
+];
+
+const P_FOLLOWERS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "details", "dialog", "div", "dl", "fieldset", "figcaption", "figure",
+    "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hgroup", "hr", "main", "menu", "nav", "ol", "p",
+    "pre", "search", "section", "table", "ul",
+];
+
+// the optional end tags of the html standard
+const END_TAG_OMISSIONS: &[Rule] = &[
+    Rule {
+        tag: "html",
+        description: "If the html element is not immediately followed by a comment",
+        excluded_next_comment: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "head",
+        description: "If the head element is not immediately followed by ASCII whitespace or a comment",
+        excluded_next_comment: true,
+        excluded_next_whitespace: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "body",
+        description: "If the body element is not immediately followed by a comment",
+        excluded_next_comment: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "li",
+        description: "If the li element is immediately followed by another li element or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["li"],
+        ..NEVER
+    },
+    Rule {
+        tag: "dt",
+        description: "If the dt element is immediately followed by another dt element or a dd element",
+        next_tags: &["dd", "dt"],
+        ..NEVER
+    },
+    Rule {
+        tag: "dd",
+        description: "If the dd element is immediately followed by another dd element or a dt element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["dd", "dt"],
+        ..NEVER
+    },
+    Rule {
+        tag: "p",
+        description: "If the p element is immediately followed by an address, article, aside, blockquote, details, dialog, div, dl, fieldset, figcaption, figure, footer, form, h1, h2, h3, h4, h5, h6, header, hgroup, hr, main, menu, nav, ol, p, pre, search, section, table, or ul element, or if there is no more content in the parent element and the parent element is not an a, audio, del, ins, map, noscript, or video element, or an autonomous custom element",
+        at_parent_end: true,
+        excluded_parents: &["a", "audio", "del", "ins", "map", "noscript", "video"],
+        next_tags: P_FOLLOWERS,
+        ..NEVER
+    },
+    Rule {
+        tag: "rt",
+        description: "If the rt element is immediately followed by an rt or rp element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["rp", "rt"],
+        ..NEVER
+    },
+    Rule {
+        tag: "rp",
+        description: "If the rp element is immediately followed by an rt or rp element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["rp", "rt"],
+        ..NEVER
+    },
+    Rule {
+        tag: "optgroup",
+        description: "If the optgroup element is immediately followed by another optgroup element, if it is immediately followed by an hr element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["hr", "optgroup"],
+        ..NEVER
+    },
+    Rule {
+        tag: "option",
+        description: "If the option element is immediately followed by another option element, if it is immediately followed by an optgroup element, if it is immediately followed by an hr element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["hr", "optgroup", "option"],
+        ..NEVER
+    },
+    Rule {
+        tag: "colgroup",
+        description: "If the colgroup element is not immediately followed by ASCII whitespace or a comment",
+        excluded_next_comment: true,
+        excluded_next_whitespace: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "caption",
+        description: "If the caption element is not immediately followed by ASCII whitespace or a comment",
+        excluded_next_comment: true,
+        excluded_next_whitespace: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "thead",
+        description: "If the thead element is immediately followed by a tbody or tfoot element",
+        next_tags: &["tbody", "tfoot"],
+        ..NEVER
+    },
+    Rule {
+        tag: "tbody",
+        description: "If the tbody element is immediately followed by a tbody or tfoot element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["tbody", "tfoot"],
+        ..NEVER
+    },
+    Rule {
+        tag: "tfoot",
+        description: "If there is no more content in the parent element",
+        at_parent_end: true,
+        ..NEVER
+    },
+    Rule {
+        tag: "tr",
+        description: "If the tr element is immediately followed by another tr element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["tr"],
+        ..NEVER
+    },
+    Rule {
+        tag: "td",
+        description: "If the td element is immediately followed by a td or th element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["td", "th"],
+        ..NEVER
+    },
+    Rule {
+        tag: "th",
+        description: "If the th element is immediately followed by a td or th element, or if there is no more content in the parent element",
+        at_parent_end: true,
+        next_tags: &["td", "th"],
+        ..NEVER
+    },
+];
+
+pub fn tag_syntax(tag: &str) -> TagSyntax {
+    let kind = ELEMENT_KINDS.iter()
+        .find(|(name, _)| *name == tag)
+        .map(|(_, kind)| *kind)
+        .unwrap_or_default();
+
+    TagSyntax {
+        kind,
+        start_tag_omission: omission_rule(START_TAG_OMISSIONS, tag),
+        end_tag_omission: omission_rule(END_TAG_OMISSIONS, tag),
+    }
+}
+
+// the svg namespace's tags are all foreign elements
+#[cfg(feature = "reffy")]
+pub fn svg_tag_syntax() -> TagSyntax {
+    TagSyntax { kind: Foreign, ..TagSyntax::default() }
+}
+
+fn omission_rule(rules: &[Rule], tag: &str) -> Option<OmissionRule> {
+    let rule = rules.iter().find(|rule| rule.tag == tag)?;
+
+    Some(OmissionRule {
+        description: Description::from(rule.description),
+        when_empty: rule.when_empty,
+        at_parent_end: rule.at_parent_end,
+        excluded_parents: rule.excluded_parents.iter().map(ToString::to_string).collect(),
+        next_tags: rule.next_tags.iter().map(ToString::to_string).collect(),
+        next_element: rule.next_element,
+        excluded_next_tags: rule.excluded_next_tags.iter().map(ToString::to_string).collect(),
+        excluded_next_comment: rule.excluded_next_comment,
+        excluded_next_whitespace: rule.excluded_next_whitespace,
+    })
+}
@@ -1,4 +1,4 @@
-#![feature(drain_filter)]
+#![cfg_attr(feature = "build", feature(drain_filter))]
 
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
@@ -9,16 +9,21 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::items_after_statements)]
 #![allow(clippy::wildcard_imports)]
+// the scraping and http dependencies pull in several versions of the same crates
+#![allow(clippy::multiple_crate_versions)]
 // ==============================================================
 
 #![doc = include_str ! ("../readme.md")]
 
 #[macro_use]
 extern crate anyhow;
+#[cfg(any(feature = "build", feature = "analyze"))]
 #[macro_use]
 extern crate lazy_regex;
+#[cfg(any(feature = "build", feature = "analyze"))]
 #[macro_use]
 extern crate lazy_static;
+#[cfg(any(feature = "build", feature = "analyze"))]
 #[macro_use]
 extern crate maplit;
 
@@ -26,34 +31,44 @@ use std::fs;
 
 use anyhow::Result;
 
-#[cfg(all(feature = "analyze", not(feature = "build")))]
+#[cfg(all(feature = "analyze", not(any(feature = "build", feature = "reffy"))))]
 use crate::analyze::run_factory;
-#[cfg(all(feature = "build", not(feature = "analyze")))]
+#[cfg(all(feature = "build", not(any(feature = "analyze", feature = "reffy"))))]
 use crate::factory::run_factory;
+#[cfg(all(feature = "reffy", not(any(feature = "analyze", feature = "build"))))]
+use crate::reffy::run_factory;
 
 #[cfg(feature = "analyze")]
 mod analyze;
+#[cfg(any(feature = "build", feature = "reffy"))]
+mod changelog;
 #[cfg(feature = "build")]
 mod factory;
+#[cfg(any(feature = "build", feature = "reffy"))]
+mod lookup;
+#[cfg(feature = "reffy")]
+mod reffy;
 
+// w3schools is only scraped by `build` and `analyze`, `reffy` reads a local crawl
+#[cfg(any(feature = "build", feature = "analyze"))]
 mod common;
+#[cfg(any(feature = "build", feature = "analyze"))]
 mod ignored;
+#[cfg(any(feature = "build", feature = "analyze"))]
 mod types;
 
 const DEBUG_TEXT_FILE: &str = "debug.txt";
 
 // retrieved html documents are cached when online
 // cached files are used unless REFRESH is true
+#[cfg(any(feature = "build", feature = "analyze"))]
 const REFRESH: bool = false;
 
-// todo scrape language code
-// todo scrape country codes
-// todo scrape event objects
 // todo scrape methods
 
 fn main() -> Result<()> {
     // for debugging
-    fs::remove_file(DEBUG_TEXT_FILE).map_or((), |_err| ());
+    let _ = fs::remove_file(DEBUG_TEXT_FILE);
 
     run_factory()
 }
@@ -0,0 +1,555 @@
+use anyhow::Result;
+
+use web_reference::prelude::*;
+
+use crate::lookup;
+use crate::reffy::css;
+use crate::reffy::svg;
+use crate::reffy::extracts::*;
+
+const GLOBAL: &str = "global";
+
+// interfaces every html element implements, their content attributes and event handlers are global
+const GLOBAL_INTERFACES: [&str; 2] = ["Element", "HTMLElement"];
+
+const EVENT_HANDLER_TYPES: [&str; 3] = ["EventHandler", "OnBeforeUnloadEventHandler", "OnErrorEventHandler"];
+
+const WINDOW_EVENT_HANDLERS: &str = "WindowEventHandlers";
+
+const EVENT_OBJECT_CATEGORIES: [(&str, EventCategory); 6] = [
+    ("ClipboardEvent", EventCategory::ClipboardEvents),
+    ("DragEvent", EventCategory::DragEvents),
+    ("KeyboardEvent", EventCategory::KeyboardEvents),
+    ("MouseEvent", EventCategory::MouseEvents),
+    ("PointerEvent", EventCategory::MouseEvents),
+    ("WheelEvent", EventCategory::MouseEvents),
+];
+
+const FORM_TARGETS: [&str; 4] = ["HTMLFormElement", "HTMLInputElement", "HTMLSelectElement", "HTMLTextAreaElement"];
+const MEDIA_TARGETS: [&str; 3] = ["HTMLAudioElement", "HTMLMediaElement", "HTMLVideoElement"];
+
+// tags the scraped reference groups apart from the spec page defining them
+const TAG_CATEGORIES: [(TagCategory, &[&str]); 6] = [
+    (TagCategory::Basic, &["html", "head", "title", "body", "h1", "h2", "h3", "h4", "h5", "h6", "p", "br", "hr"]),
+    (TagCategory::Frames, &["iframe"]),
+    (TagCategory::Links, &["a", "link", "nav"]),
+    (TagCategory::Lists, &["ul", "ol", "li", "dl", "dt", "dd", "menu"]),
+    (TagCategory::Meta, &["meta", "base"]),
+    (TagCategory::Programming, &["script", "noscript", "embed", "object"]),
+];
+
+// multipage spec pages, i.e. `text-level-semantics.html#the-a-element`, and the category of the tags they define
+const PAGE_CATEGORIES: [(&str, TagCategory); 15] = [
+    ("canvas", TagCategory::Images),
+    ("edits", TagCategory::Formatting),
+    ("embedded-content", TagCategory::Images),
+    ("form-elements", TagCategory::FormsInput),
+    ("forms", TagCategory::FormsInput),
+    ("grouping-content", TagCategory::StylesSemantics),
+    ("iframe-embed-object", TagCategory::Frames),
+    ("image-maps", TagCategory::Images),
+    ("input", TagCategory::FormsInput),
+    ("interactive-elements", TagCategory::StylesSemantics),
+    ("media", TagCategory::AudioVideo),
+    ("scripting", TagCategory::Programming),
+    ("sections", TagCategory::StylesSemantics),
+    ("tables", TagCategory::Tables),
+    ("text-level-semantics", TagCategory::Formatting),
+];
+
+pub type Member<'a> = (&'a str, &'a IdlMember);
+
+#[derive(Debug, Default)]
+struct Interface<'a> {
+    inheritance: Option<&'a str>,
+    members: Vec<&'a IdlMember>,
+    includes: Vec<&'a str>,
+}
+
+// interfaces and mixins of all idl extracts, with their partial definitions merged
+#[derive(Debug, Default)]
+pub struct Interfaces<'a>(Map<&'a str, Interface<'a>>);
+
+impl<'a> Interfaces<'a> {
+    pub fn new(idl: &'a [IdlExtract]) -> Self {
+        let mut interfaces = Self::default();
+
+        for parsed in idl.iter().map(|extract| &extract.idlparsed) {
+            let definitions = parsed.idl_names.values()
+                .chain(parsed.idl_extended_names.values().flatten());
+
+            for definition in definitions {
+                interfaces.add(definition);
+            }
+        }
+
+        interfaces
+    }
+
+    fn add(&mut self, definition: &'a IdlDefinition) {
+        match definition.kind.as_str() {
+            "includes" => if let (Some(target), Some(includes)) = (&definition.target, &definition.includes) {
+                self.0.entry(target.as_str()).or_default().includes.push(includes.as_str());
+            },
+            "interface" | "interface mixin" => {
+                let interface = self.0.entry(definition.name.as_str()).or_default();
+
+                interface.inheritance = interface.inheritance.or(definition.inheritance.as_deref());
+                interface.members.extend(&definition.members);
+            }
+            _ => {}
+        }
+    }
+
+    // members of an interface and of the mixins it includes, with the name of the interface declaring them
+    fn members(&self, name: &'a str) -> Vec<Member<'a>> {
+        let Some(interface) = self.0.get(name) else {
+            return Vec::new();
+        };
+
+        let mut members = interface.members.iter()
+            .map(|member| (name, *member))
+            .collect::<Vec<_>>();
+
+        for mixin in &interface.includes {
+            members.extend(self.members(mixin));
+        }
+
+        members
+    }
+
+    fn global_members(&self) -> Vec<Member<'a>> {
+        GLOBAL_INTERFACES.iter().flat_map(|name| self.members(name)).collect()
+    }
+
+    // members of an interface and the interfaces it inherits from, short of the `until` interfaces
+    pub fn inherited_members(&self, name: &'a str, until: &[&str]) -> Vec<Member<'a>> {
+        let mut chain = Vec::new();
+        let mut next = Some(name);
+
+        while let Some(name) = next.filter(|name| !until.contains(name) && !chain.contains(name)) {
+            chain.push(name);
+            next = self.0.get(name).and_then(|interface| interface.inheritance);
+        }
+
+        chain.into_iter().flat_map(|name| self.members(name)).collect()
+    }
+
+    fn specific_members(&self, element: &'a ReffyElement) -> Vec<Member<'a>> {
+        element.interface.as_deref()
+            .map(|interface| self.inherited_members(interface, &GLOBAL_INTERFACES))
+            .unwrap_or_default()
+    }
+}
+
+// an event as described by all the specs firing it
+#[derive(Debug, Default)]
+struct EventSummary<'a> {
+    bubbles: bool,
+    cancelable: bool,
+    objects: HasEventObjects,
+    targets: Set<&'a str>,
+}
+
+pub fn build_reference(crawl: &Crawl) -> Result<WebReference> {
+    let interfaces = Interfaces::new(&crawl.idl);
+    let elements = html_elements(crawl);
+
+    if elements.is_empty() {
+        bail!("Could not find any html elements in the reffy crawl");
+    }
+
+    if interfaces.0.is_empty() {
+        bail!("Could not find any idl interfaces in the reffy crawl");
+    }
+
+    let tags = build_tags(&elements, &interfaces);
+    let tags_categorized = categorize_tags(&elements);
+
+    let aria_attributes = lookup::aria_attributes();
+
+    let attributes = build_attributes(&elements, &interfaces, &aria_attributes);
+    let attributes_categorized = categorize_attributes(&attributes);
+
+    let (events, events_categorized) = build_events(crawl, &elements, &interfaces);
+    let event_objects = build_event_objects(&events, &interfaces);
+
+    let (css_properties, css_categorized) = css::build_css(&crawl.css);
+    let (svg_tags, svg_attributes, svg_attributes_categorized) = svg::build_svg(crawl, &interfaces);
+
+    Ok(WebReference {
+        format_version: FORMAT_VERSION,
+        aria_attributes,
+        aria_roles: lookup::aria_roles(),
+        attributes,
+        attributes_categorized,
+        countries: lookup::lookup_countries(),
+        css_categorized,
+        css_properties,
+        event_objects,
+        events,
+        events_categorized,
+        languages: lookup::lookup_languages(),
+        supported_browsers: TagsSupport::default(),
+        svg_attributes,
+        svg_attributes_categorized,
+        svg_tags,
+        tags,
+        tags_categorized,
+    })
+}
+
+fn build_attributes(elements: &[&ReffyElement], interfaces: &Interfaces, aria_attributes: &AriaAttributes) -> Attributes {
+    let mut attributes = Attributes::default();
+
+    for (_, member) in interfaces.global_members() {
+        if let Some(name) = reflected_attribute(member) {
+            let attribute = build_attribute(&name, AttributeBelongsTo::Global, member);
+
+            attributes.entry(name).or_default().insert(String::from(GLOBAL), attribute);
+        }
+    }
+
+    for element in elements {
+        for (_, member) in interfaces.specific_members(element) {
+            if let Some(name) = reflected_attribute(member) {
+                let belongs_to = AttributeBelongsTo::Tags(std::iter::once(element.name.clone()).collect());
+                let attribute = build_attribute(&name, belongs_to, member);
+
+                attributes.entry(name).or_default().insert(element.name.clone(), attribute);
+            }
+        }
+    }
+
+    // the idl reflects the aria states and properties as strings, the lookup knows their values
+    attributes.extend(lookup::aria_global_attributes(aria_attributes));
+
+    for tags in attributes.values_mut() {
+        tags.sort_keys();
+    }
+
+    attributes.sort_keys();
+
+    attributes
+}
+
+fn build_attribute(name: &str, belongs_to: AttributeBelongsTo, member: &IdlMember) -> Attribute {
+    Attribute {
+        name: name.to_string(),
+        belongs_to,
+        description: Description::default(),
+        supported: SupportedBrowsers::default(),
+        values: attribute_value(member),
+    }
+}
+
+fn build_events(crawl: &Crawl, elements: &[&ReffyElement], interfaces: &Interfaces) -> (Events, EventsCategorized) {
+    let summaries = summarize_events(crawl);
+
+    let mut events = Events::default();
+    let mut events_categorized = EventsCategorized::default();
+
+    let global = interfaces.global_members().into_iter()
+        .map(|member| (member, None));
+
+    let specific = elements.iter()
+        .flat_map(|element| interfaces.specific_members(element).into_iter().map(|member| (member, Some(&element.name))));
+
+    for ((declared_in, member), tag) in global.chain(specific).filter(|((_, member), _)| is_event_handler(member)) {
+        let event = events.entry(member.name.clone()).or_insert_with(|| {
+            let summary = summaries.get(member.name.trim_start_matches("on"));
+            let category = event_category(declared_in, summary);
+
+            events_categorized.entry(category)
+                .or_insert_with(|| EventCategoryDetails { category, description: Description::default(), events: HasEvents::default() })
+                .events.insert(member.name.clone());
+
+            Event {
+                name: member.name.clone(),
+                belongs_to: tag.map_or(EventBelongsTo::AllVisible, |_| EventBelongsTo::Tags(BelongsToTags::default())),
+                description: Description::default(),
+                details: EventDetails {
+                    bubbles: summary.is_some_and(|summary| summary.bubbles),
+                    cancelable: summary.is_some_and(|summary| summary.cancelable),
+                },
+                event_objects: summary.map(|summary| summary.objects.clone()).unwrap_or_default(),
+                supported: SupportedBrowsers::default(),
+                deprecated: AlternativeEvents::default(),
+            }
+        });
+
+        // a handler every element has is not narrowed down by the elements redeclaring it, i.e. `<body>`
+        if let (EventBelongsTo::Tags(tags), Some(tag)) = (&mut event.belongs_to, tag) {
+            tags.insert(tag.clone());
+        }
+    }
+
+    for category in events_categorized.values_mut() {
+        category.events.sort();
+    }
+
+    events.sort_keys();
+    events_categorized.sort_keys();
+
+    (events, events_categorized)
+}
+
+// the objects passed to event handlers, with the fields and methods they inherit, i.e. `MouseEvent` from `UIEvent`
+fn build_event_objects(events: &Events, interfaces: &Interfaces) -> EventObjects {
+    let mut names = events.values()
+        .flat_map(|event| &event.event_objects)
+        .collect::<Vec<_>>();
+
+    names.sort_unstable();
+    names.dedup();
+
+    names.into_iter()
+        .filter(|name| interfaces.0.contains_key(name.as_str()))
+        .map(|name| {
+            let mut fields = Fields::default();
+            let mut methods = Methods::default();
+
+            for (_, member) in interfaces.inherited_members(name, &[]) {
+                if member.name.is_empty() || member.special == "static" {
+                    continue;
+                }
+
+                match member.kind.as_str() {
+                    "attribute" if !fields.contains_key(&member.name) => {
+                        let field = Field {
+                            name: member.name.clone(),
+                            description: Description::default(),
+                            r#type: value_type(member.idl_type.as_ref()),
+                            default: String::new(),
+                        };
+
+                        fields.insert(field.name.clone(), field);
+                    }
+                    "operation" if !methods.contains_key(&member.name) => {
+                        let parameter = member.arguments.iter()
+                            .map(|argument| {
+                                let parameter = Parameter {
+                                    name: argument.name.clone(),
+                                    description: Description::default(),
+                                    r#type: value_type(argument.idl_type.as_ref()),
+                                    default: argument.default.as_ref().map(IdlDefault::to_source).unwrap_or_default(),
+                                };
+
+                                (parameter.name.clone(), parameter)
+                            })
+                            .collect();
+
+                        let method = Method { name: member.name.clone(), description: Description::default(), parameter };
+
+                        methods.insert(method.name.clone(), method);
+                    }
+                    _ => {}
+                }
+            }
+
+            fields.sort_keys();
+            methods.sort_keys();
+
+            let object = Object { name: name.clone(), description: Description::default(), fields, methods };
+
+            (object.name.clone(), object)
+        })
+        .collect()
+}
+
+fn build_tags(elements: &[&ReffyElement], interfaces: &Interfaces) -> Tags {
+    elements.iter()
+        .map(|element| {
+            let attributes = interfaces.specific_members(element).into_iter()
+                .filter_map(|(_, member)| reflected_attribute(member))
+                .collect();
+
+            let tag = Tag {
+                name: element.name.clone(),
+                description: Description::default(),
+                supported: SupportedBrowsers::default(),
+                attributes,
+                optional_attributes: HasAttributes::default(),
+                global_attributes: true,
+                global_events: true,
+                alternatives: css_alternatives(&element.name),
+                aria: lookup::tag_aria(&element.name),
+                content_model: lookup::content_model(&element.name),
+                syntax: lookup::tag_syntax(&element.name),
+            };
+
+            (tag.name.clone(), tag)
+        })
+        .collect()
+}
+
+fn css_alternatives(tag: &str) -> TagAlternatives {
+    let properties = lookup::css_alternatives(tag);
+
+    if properties.is_empty() { TagAlternatives::None } else { TagAlternatives::Css(properties) }
+}
+
+fn categorize_attributes(attributes: &Attributes) -> AttributesCategorized {
+    let mut attributes_categorized = AttributesCategorized::default();
+
+    for (name, tags) in attributes {
+        for category in tags.keys().map(|tag| if tag == GLOBAL { AttributeCategory::GlobalAttributes } else { AttributeCategory::SpecificToTags }) {
+            attributes_categorized.entry(category)
+                .or_insert_with(|| AttributeCategoryDetails { category, attributes: HasAttributes::default() })
+                .attributes.insert(name.clone());
+        }
+    }
+
+    attributes_categorized.sort_keys();
+
+    attributes_categorized
+}
+
+fn categorize_tags(elements: &[&ReffyElement]) -> TagsCategorized {
+    let mut tags_categorized = TagsCategorized::default();
+
+    for element in elements.iter().filter(|element| !element.obsolete) {
+        let category = tag_category(element);
+
+        tags_categorized.entry(category)
+            .or_insert_with(|| TagCategoryDetails { category, tags: HasTags::default() })
+            .tags.insert(element.name.clone());
+    }
+
+    tags_categorized.sort_keys();
+
+    tags_categorized
+}
+
+fn attribute_value(member: &IdlMember) -> AttributeValue {
+    let description = Description::default();
+    let reflects_url = member.ext_attrs.iter().any(|attribute| attribute.name == "ReflectURL");
+
+    match member.idl_type.as_ref().and_then(IdlType::name) {
+        Some("boolean") =>
+            AttributeValue::Boolean { description },
+        Some("USVString") =>
+            AttributeValue::URL { description },
+        _ if reflects_url =>
+            AttributeValue::URL { description },
+        Some("double" | "long" | "short" | "unrestricted double" | "unsigned long" | "unsigned short") =>
+            AttributeValue::Number { description },
+        _ =>
+            AttributeValue::Text { description },
+    }
+}
+
+// the closest javascript type of an idl type, i.e. `DOMHighResTimeStamp` is a `double`
+fn value_type(idl_type: Option<&IdlType>) -> Type {
+    const NUMBER_TYPES: [&str; 13] = [
+        "byte", "double", "DOMHighResTimeStamp", "float", "long", "long long", "octet", "short",
+        "unrestricted double", "unrestricted float", "unsigned long", "unsigned long long", "unsigned short",
+    ];
+    const STRING_TYPES: [&str; 4] = ["ByteString", "CSSOMString", "DOMString", "USVString"];
+
+    let Some(idl_type) = idl_type else {
+        return Type::Unknown;
+    };
+
+    if matches!(idl_type.generic.as_str(), "sequence" | "FrozenArray" | "ObservableArray") {
+        return Type::Array;
+    }
+
+    match idl_type.name() {
+        Some("boolean") => Type::Boolean,
+        Some(name) if NUMBER_TYPES.contains(&name) => Type::Number,
+        Some(name) if STRING_TYPES.contains(&name) => Type::String,
+        _ => Type::Object,
+    }
+}
+
+fn event_category(declared_in: &str, summary: Option<&EventSummary>) -> EventCategory {
+    if declared_in == WINDOW_EVENT_HANDLERS {
+        return EventCategory::WindowEvents;
+    }
+
+    let Some(summary) = summary else {
+        return EventCategory::MiscellaneousEvents;
+    };
+
+    let by_object = EVENT_OBJECT_CATEGORIES.iter()
+        .find(|(object, _)| summary.objects.contains(*object))
+        .map(|(_, category)| *category);
+
+    by_object.unwrap_or_else(|| {
+        if summary.targets.iter().any(|target| MEDIA_TARGETS.contains(target)) {
+            EventCategory::MediaEvents
+        } else if summary.targets.iter().any(|target| FORM_TARGETS.contains(target)) {
+            EventCategory::FormEvents
+        } else {
+            EventCategory::MiscellaneousEvents
+        }
+    })
+}
+
+// html elements by name, the first spec defining an element wins
+fn html_elements(crawl: &Crawl) -> Vec<&ReffyElement> {
+    let mut elements = crawl.elements.iter()
+        .flat_map(|extract| &extract.elements)
+        .filter(|element| element.interface.as_deref().is_some_and(|interface| interface.starts_with("HTML")))
+        .fold(Map::<&str, &ReffyElement>::default(), |mut elements, element| {
+            elements.entry(element.name.as_str()).or_insert(element);
+
+            elements
+        });
+
+    elements.sort_keys();
+
+    elements.into_values().collect()
+}
+
+fn is_event_handler(member: &IdlMember) -> bool {
+    member.kind == "attribute" &&
+        member.idl_type.as_ref().and_then(IdlType::name).is_some_and(|name| EVENT_HANDLER_TYPES.contains(&name))
+}
+
+// the content attribute an idl attribute reflects, i.e. `htmlFor` reflects `for`
+fn reflected_attribute(member: &IdlMember) -> Option<String> {
+    if member.kind != "attribute" {
+        return None;
+    }
+
+    let reflect = member.ext_attrs.iter()
+        .find(|attribute| attribute.name.starts_with("Reflect") && attribute.name != "ReflectSetter")?;
+
+    let renamed = reflect.rhs.as_ref()
+        .filter(|_| reflect.name == "Reflect")
+        .and_then(IdlExtendedAttributeValue::as_str);
+
+    Some(renamed.map_or_else(|| member.name.to_ascii_lowercase(), String::from))
+}
+
+fn summarize_events(crawl: &Crawl) -> Map<&str, EventSummary<'_>> {
+    let mut summaries = Map::<&str, EventSummary>::default();
+
+    for event in crawl.events.iter().flat_map(|extract| &extract.events) {
+        let summary = summaries.entry(event.name.as_str()).or_default();
+
+        summary.bubbles |= event.targets.iter().any(|target| target.bubbles.unwrap_or_default());
+        summary.cancelable |= event.cancelable.unwrap_or_default();
+        summary.objects.extend(event.interface.iter().cloned());
+        summary.targets.extend(event.targets.iter().map(|target| target.target.as_str()));
+    }
+
+    summaries
+}
+
+fn tag_category(element: &ReffyElement) -> TagCategory {
+    if let Some((category, _)) = TAG_CATEGORIES.iter().find(|(_, tags)| tags.contains(&element.name.as_str())) {
+        return *category;
+    }
+
+    // i.e. `https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element`
+    let page = element.href.split('#').next().unwrap_or_default()
+        .rsplit('/').next().unwrap_or_default()
+        .trim_end_matches(".html");
+
+    PAGE_CATEGORIES.iter()
+        .find(|(name, _)| *name == page)
+        .map_or(TagCategory::StylesSemantics, |(_, category)| *category)
+}
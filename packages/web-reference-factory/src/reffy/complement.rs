@@ -0,0 +1,110 @@
+use web_reference::prelude::*;
+
+// the spec extracts have neither descriptions nor browser support, both are carried over from a
+// previously generated reference for the tags, attributes, events, event objects, css properties and svg
+// tags and attributes it has in common
+pub fn complement_reference(reference: &mut WebReference, previous: &WebReference) {
+    for (name, tag) in &mut reference.tags {
+        if let Some(previous) = previous.tags.get(name) {
+            complement_description(&mut tag.description, &previous.description);
+            complement_supported(&mut tag.supported, &previous.supported);
+
+            if tag.alternatives == TagAlternatives::None {
+                tag.alternatives = previous.alternatives.clone();
+            }
+        }
+    }
+
+    for (name, tags) in &mut reference.attributes {
+        for (tag, attribute) in tags {
+            let Some(previous) = previous.attributes.get(name).and_then(|tags| tags.get(tag)) else {
+                continue;
+            };
+
+            complement_description(&mut attribute.description, &previous.description);
+            complement_supported(&mut attribute.supported, &previous.supported);
+
+            // keywords are only documented by the previous reference
+            if matches!(previous.values, AttributeValue::Values(_)) {
+                attribute.values = previous.values.clone();
+            }
+        }
+    }
+
+    for (name, tag) in &mut reference.svg_tags {
+        if let Some(previous) = previous.svg_tags.get(name) {
+            complement_description(&mut tag.description, &previous.description);
+            complement_supported(&mut tag.supported, &previous.supported);
+        }
+    }
+
+    for (name, tags) in &mut reference.svg_attributes {
+        for (tag, attribute) in tags {
+            if let Some(previous) = previous.svg_attributes.get(name).and_then(|tags| tags.get(tag)) {
+                complement_description(&mut attribute.description, &previous.description);
+                complement_supported(&mut attribute.supported, &previous.supported);
+            }
+        }
+    }
+
+    for (name, event) in &mut reference.events {
+        if let Some(previous) = previous.events.get(name) {
+            complement_description(&mut event.description, &previous.description);
+            complement_supported(&mut event.supported, &previous.supported);
+
+            if event.deprecated.is_empty() {
+                event.deprecated = previous.deprecated.clone();
+            }
+        }
+    }
+
+    for (name, object) in &mut reference.event_objects {
+        let Some(previous) = previous.event_objects.get(name) else {
+            continue;
+        };
+
+        complement_description(&mut object.description, &previous.description);
+
+        for (name, field) in &mut object.fields {
+            if let Some(previous) = previous.fields.get(name) {
+                complement_description(&mut field.description, &previous.description);
+            }
+        }
+
+        for (name, method) in &mut object.methods {
+            if let Some(previous) = previous.methods.get(name) {
+                complement_description(&mut method.description, &previous.description);
+            }
+        }
+    }
+
+    for (name, property) in &mut reference.css_properties {
+        if let Some(previous) = previous.css_properties.get(name) {
+            complement_description(&mut property.description, &previous.description);
+            complement_supported(&mut property.supported, &previous.supported);
+        }
+    }
+
+    for (category, details) in &mut reference.events_categorized {
+        if let Some(previous) = previous.events_categorized.get(category) {
+            complement_description(&mut details.description, &previous.description);
+        }
+    }
+
+    reference.supported_browsers = previous.supported_browsers.iter()
+        .filter(|(tag, _)| reference.tags.contains_key(*tag))
+        .map(|(tag, supported)| (tag.clone(), supported.clone()))
+        .collect();
+}
+
+fn complement_description(description: &mut Description, previous: &Description) {
+    if description.is_empty() {
+        *description = previous.clone();
+    }
+}
+
+fn complement_supported(supported: &mut SupportedBrowsers, previous: &SupportedBrowsers) {
+    if supported.supported.is_empty() {
+        *supported = previous.clone();
+    }
+}
@@ -0,0 +1,80 @@
+use web_reference::prelude::*;
+
+use crate::lookup;
+use crate::reffy::extracts::*;
+
+// properties grouped apart from the spec defining them, the first match wins
+const PROPERTY_PREFIX_CATEGORIES: [(&str, CssCategory); 5] = [
+    ("border-collapse", CssCategory::Table),
+    ("border-spacing", CssCategory::Table),
+    ("border", CssCategory::Border),
+    ("color", CssCategory::Color),
+    ("list-style", CssCategory::List),
+];
+
+// keywords of spec titles, i.e. `CSS Backgrounds and Borders Module Level 3`, and the category of
+// the properties they define, the first match wins
+const SPEC_CATEGORIES: [(&str, CssCategory); 14] = [
+    ("Animations", CssCategory::Animation),
+    ("Transitions", CssCategory::Animation),
+    ("Backgrounds", CssCategory::Background),
+    ("Flexible Box", CssCategory::Flexbox),
+    ("Box", CssCategory::BoxModel),
+    ("Color", CssCategory::Color),
+    ("Fonts", CssCategory::Font),
+    ("Grid", CssCategory::Grid),
+    ("Lists", CssCategory::List),
+    ("Position", CssCategory::Positioning),
+    ("Table", CssCategory::Table),
+    ("Text", CssCategory::Text),
+    ("Transforms", CssCategory::Transform),
+    ("Display", CssCategory::BoxModel),
+];
+
+const INHERITED: &str = "yes";
+
+pub fn build_css(extracts: &[CssExtract]) -> (CssProperties, CssCategorized) {
+    let mut properties = CssProperties::default();
+    let mut categorized = CssCategorized::default();
+
+    for extract in extracts {
+        // properties extended by a spec, i.e. with `new_values`, are defined by another
+        for property in extract.properties.iter().filter(|property| property.new_values.is_none()) {
+            if properties.contains_key(&property.name) {
+                continue;
+            }
+
+            let category = css_category(&property.name, &extract.spec.title);
+
+            categorized.entry(category)
+                .or_insert_with(|| CssCategoryDetails { category, properties: HasCssProperties::default() })
+                .properties.insert(property.name.clone());
+
+            properties.insert(property.name.clone(), CssProperty {
+                name: property.name.clone(),
+                description: Description::default(),
+                syntax: property.value.clone().unwrap_or_default(),
+                initial: property.initial.clone().unwrap_or_default(),
+                inherited: property.inherited.as_deref() == Some(INHERITED),
+                supported: SupportedBrowsers::default(),
+                presentational_attributes: lookup::presentational_attributes(&property.name),
+            });
+        }
+    }
+
+    properties.sort_keys();
+    categorized.sort_keys();
+
+    for details in categorized.values_mut() {
+        details.properties.sort();
+    }
+
+    (properties, categorized)
+}
+
+fn css_category(property: &str, spec: &str) -> CssCategory {
+    PROPERTY_PREFIX_CATEGORIES.iter()
+        .find(|(prefix, _)| property.starts_with(prefix))
+        .or_else(|| SPEC_CATEGORIES.iter().find(|(keyword, _)| spec.contains(keyword)))
+        .map_or(CssCategory::Misc, |(_, category)| *category)
+}
@@ -0,0 +1,276 @@
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+use web_reference::prelude::Map;
+
+const CSS_PATH: &str = "ed/css";
+const ELEMENTS_PATH: &str = "ed/elements";
+const EVENTS_PATH: &str = "ed/events";
+
+// the parsed json form of the `ed/idl` extracts, the raw `.idl` files are not read
+const IDL_PATH: &str = "ed/idlparsed";
+
+// the extracts of a reffy crawl the factory builds a reference from
+#[derive(Debug)]
+pub struct Crawl {
+    pub css: Vec<CssExtract>,
+    pub elements: Vec<ElementsExtract>,
+    pub events: Vec<EventsExtract>,
+    pub idl: Vec<IdlExtract>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CssExtract {
+    #[serde(default)]
+    pub spec: ReffySpec,
+
+    #[serde(default)]
+    pub properties: Vec<ReffyCssProperty>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReffySpec {
+    // i.e. `CSS Text Module Level 3`
+    #[serde(default)]
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReffyCssProperty {
+    pub name: String,
+
+    // the value definition syntax, missing for properties a spec only extends with `new_values`
+    #[serde(default)]
+    pub value: Option<String>,
+
+    #[serde(default)]
+    pub new_values: Option<String>,
+
+    #[serde(default)]
+    pub initial: Option<String>,
+
+    // `yes` or `no`
+    #[serde(default)]
+    pub inherited: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ElementsExtract {
+    #[serde(default)]
+    pub elements: Vec<ReffyElement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReffyElement {
+    pub name: String,
+
+    #[serde(default)]
+    pub href: String,
+
+    #[serde(default)]
+    pub interface: Option<String>,
+
+    #[serde(default)]
+    pub obsolete: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventsExtract {
+    #[serde(default)]
+    pub events: Vec<ReffyEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReffyEvent {
+    #[serde(rename = "type")]
+    pub name: String,
+
+    #[serde(default)]
+    pub interface: Option<String>,
+
+    #[serde(default)]
+    pub targets: Vec<ReffyEventTarget>,
+
+    #[serde(default)]
+    pub cancelable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReffyEventTarget {
+    pub target: String,
+
+    #[serde(default)]
+    pub bubbles: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlExtract {
+    #[serde(default)]
+    pub idlparsed: IdlParsed,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlParsed {
+    #[serde(default)]
+    pub idl_names: Map<String, IdlDefinition>,
+
+    // partial interfaces and `includes` statements, by the name of the interface they extend
+    #[serde(default)]
+    pub idl_extended_names: Map<String, Vec<IdlDefinition>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlDefinition {
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub inheritance: Option<String>,
+
+    #[serde(default)]
+    pub members: Vec<IdlMember>,
+
+    #[serde(default)]
+    pub target: Option<String>,
+
+    #[serde(default)]
+    pub includes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlMember {
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub idl_type: Option<IdlType>,
+
+    #[serde(default)]
+    pub ext_attrs: Vec<IdlExtendedAttribute>,
+
+    #[serde(default)]
+    pub arguments: Vec<IdlArgument>,
+
+    // `static` members belong to the interface object rather than its instances
+    #[serde(default)]
+    pub special: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlArgument {
+    pub name: String,
+
+    #[serde(default)]
+    pub idl_type: Option<IdlType>,
+
+    #[serde(default)]
+    pub default: Option<IdlDefault>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlDefault {
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    #[serde(default)]
+    pub value: JsonValue,
+}
+
+impl IdlDefault {
+    // i.e. `false`, `0`, `[]` or `{}`
+    pub fn to_source(&self) -> String {
+        match (self.kind.as_str(), &self.value) {
+            ("dictionary", _) => String::from("{}"),
+            ("sequence", _) => String::from("[]"),
+            (_, JsonValue::String(value)) => value.clone(),
+            (_, value) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlType {
+    // a type name, or a list of types for unions and generics
+    pub idl_type: JsonValue,
+
+    // i.e. `sequence` or `FrozenArray`
+    #[serde(default)]
+    pub generic: String,
+}
+
+impl IdlType {
+    pub fn name(&self) -> Option<&str> {
+        self.idl_type.as_str()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlExtendedAttribute {
+    pub name: String,
+
+    #[serde(default)]
+    pub rhs: Option<IdlExtendedAttributeValue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdlExtendedAttributeValue {
+    pub value: JsonValue,
+}
+
+impl IdlExtendedAttributeValue {
+    // identifiers are bare, i.e. `[Reflect=for]`, strings keep their quotes, i.e. `[Reflect="aria-atomic"]`
+    pub fn as_str(&self) -> Option<&str> {
+        self.value.as_str().map(|value| value.trim_matches('"'))
+    }
+}
+
+pub fn load_crawl(root: &Path) -> Result<Crawl> {
+    if !root.is_dir() {
+        bail!("Could not find reffy crawl output at {}", root.display());
+    }
+
+    Ok(Crawl {
+        css: load_extracts(&root.join(CSS_PATH))?,
+        elements: load_extracts(&root.join(ELEMENTS_PATH))?,
+        events: load_extracts(&root.join(EVENTS_PATH))?,
+        idl: load_extracts(&root.join(IDL_PATH))?,
+    })
+}
+
+// every json extract of a directory, in file name order for a deterministic reference
+fn load_extracts<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    let mut files = fs::read_dir(path)
+        .map_err(|err| anyhow!("Could not read extracts from {}\n  Err: {err}", path.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+
+    files.retain(|file| file.extension().is_some_and(|extension| extension == "json"));
+    files.sort();
+
+    files.iter()
+        .map(|file| {
+            let reader = BufReader::new(File::open(file)?);
+
+            serde_json::from_reader(reader)
+                .map_err(|err| anyhow!("Exception parsing extract {}\n  Err: {err}", file.display()))
+        })
+        .collect()
+}
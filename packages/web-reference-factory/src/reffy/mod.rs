@@ -0,0 +1,33 @@
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::changelog::{load_previous_reference, write_reference};
+
+mod build;
+mod complement;
+mod css;
+mod extracts;
+mod svg;
+
+// a checked out https://github.com/w3c/webref, or the output directory of a local reffy crawl,
+// unless one is given as the first argument
+const DEFAULT_CRAWL_PATH: &str = "webref";
+
+pub fn run_factory() -> Result<()> {
+    let crawl_path = env::args().nth(1)
+        .map_or_else(|| PathBuf::from(DEFAULT_CRAWL_PATH), PathBuf::from);
+
+    let crawl = extracts::load_crawl(&crawl_path)?;
+
+    let mut reference = build::build_reference(&crawl)?;
+
+    let previous = load_previous_reference()?;
+
+    if let Some(previous) = &previous {
+        complement::complement_reference(&mut reference, previous);
+    }
+
+    write_reference(&reference, previous.as_ref())
+}
@@ -0,0 +1,140 @@
+use web_reference::prelude::*;
+
+use crate::lookup;
+use crate::reffy::build::{Interfaces, Member};
+use crate::reffy::extracts::*;
+
+const GLOBAL: &str = "global";
+
+// interfaces every svg element implements, their attributes are the core attributes
+const SVG_GLOBAL_INTERFACES: [&str; 2] = ["Element", "SVGElement"];
+
+// the idl types of content attributes, i.e. `SVGAnimatedLength` of `x`
+const ANIMATED_TYPE_PREFIX: &str = "SVGAnimated";
+
+pub fn build_svg<'a>(crawl: &'a Crawl, interfaces: &Interfaces<'a>) -> (Tags, Attributes, AttributesCategorized) {
+    let elements = svg_elements(crawl);
+
+    let mut tags = Tags::default();
+    let mut attributes = Attributes::default();
+
+    for (name, kind) in lookup::SVG_CORE_ATTRIBUTES {
+        let values = AttributeValue::from_kind(kind, Description::default()).unwrap_or(AttributeValue::None);
+
+        insert_attribute(&mut attributes, name, GLOBAL, AttributeBelongsTo::Global, values);
+    }
+
+    for name in lookup::SVG_PRESENTATION_ATTRIBUTES {
+        insert_attribute(&mut attributes, name, GLOBAL, AttributeBelongsTo::Global, AttributeValue::Text { description: Description::default() });
+    }
+
+    for element in elements {
+        let members = element.interface.as_deref()
+            .map(|interface| interfaces.inherited_members(interface, &SVG_GLOBAL_INTERFACES))
+            .unwrap_or_default();
+
+        let specific = lookup::SVG_SPECIFIC_ATTRIBUTES.iter()
+            .filter(|(_, tags)| tags.contains(&element.name.as_str()))
+            .map(|(name, _)| (name.to_string(), AttributeValue::Text { description: Description::default() }));
+
+        let mut tag_attributes = HasAttributes::default();
+
+        for (name, values) in members.into_iter().filter_map(animated_attribute).chain(specific) {
+            let belongs_to = AttributeBelongsTo::Tags(std::iter::once(element.name.clone()).collect());
+
+            insert_attribute(&mut attributes, &name, &element.name, belongs_to, values);
+            tag_attributes.insert(name);
+        }
+
+        tags.insert(element.name.clone(), Tag {
+            name: element.name.clone(),
+            description: Description::default(),
+            supported: SupportedBrowsers::default(),
+            attributes: tag_attributes,
+            optional_attributes: HasAttributes::default(),
+            global_attributes: true,
+            global_events: true,
+            alternatives: TagAlternatives::None,
+            aria: TagAria::default(),
+            content_model: None,
+            syntax: lookup::svg_tag_syntax(),
+        });
+    }
+
+    tags.sort_keys();
+    attributes.sort_keys();
+
+    let attributes_categorized = categorize_svg_attributes(&attributes);
+
+    (tags, attributes, attributes_categorized)
+}
+
+// the content attribute of an animated idl attribute, they share their name, i.e. `viewBox`
+fn animated_attribute((_, member): Member<'_>) -> Option<(String, AttributeValue)> {
+    let description = Description::default();
+    let idl_type = member.idl_type.as_ref().and_then(IdlType::name)?;
+
+    if member.kind != "attribute" || !idl_type.starts_with(ANIMATED_TYPE_PREFIX) {
+        return None;
+    }
+
+    let values = match (idl_type.trim_start_matches(ANIMATED_TYPE_PREFIX), member.name.as_str()) {
+        ("Boolean", _) => AttributeValue::Boolean { description },
+        ("Integer" | "Number", _) => AttributeValue::Number { description },
+        ("String", "href") => AttributeValue::URL { description },
+        _ => AttributeValue::Text { description },
+    };
+
+    Some((member.name.clone(), values))
+}
+
+fn categorize_svg_attributes(attributes: &Attributes) -> AttributesCategorized {
+    let mut categorized = AttributesCategorized::default();
+
+    for (name, tags) in attributes {
+        for tag in tags.keys() {
+            let category = if tag != GLOBAL {
+                AttributeCategory::SpecificToTags
+            } else if lookup::SVG_PRESENTATION_ATTRIBUTES.contains(&name.as_str()) {
+                AttributeCategory::PresentationAttributes
+            } else {
+                AttributeCategory::GlobalAttributes
+            };
+
+            categorized.entry(category)
+                .or_insert_with(|| AttributeCategoryDetails { category, attributes: HasAttributes::default() })
+                .attributes.insert(name.clone());
+        }
+    }
+
+    categorized.sort_keys();
+
+    categorized
+}
+
+fn insert_attribute(attributes: &mut Attributes, name: &str, tag: &str, belongs_to: AttributeBelongsTo, values: AttributeValue) {
+    let attribute = Attribute {
+        name: name.to_string(),
+        belongs_to,
+        description: Description::default(),
+        supported: SupportedBrowsers::default(),
+        values,
+    };
+
+    attributes.entry(name.to_string()).or_default().insert(tag.to_string(), attribute);
+}
+
+fn svg_elements(crawl: &Crawl) -> Vec<&ReffyElement> {
+    let mut elements = crawl.elements.iter()
+        .flat_map(|extract| &extract.elements)
+        .filter(|element| element.interface.as_deref().is_some_and(|interface| interface.starts_with("SVG")))
+        .fold(Map::<&str, &ReffyElement>::default(), |mut elements, element| {
+            elements.entry(element.name.as_str()).or_insert(element);
+
+            elements
+        });
+
+    elements.sort_keys();
+
+    elements.into_values().collect()
+}
@@ -37,6 +37,12 @@ pub type RawEventDetails = (SupportedBrowsers, RawTechDetails);
 #[cfg(feature = "build")]
 pub type RawEventNames = Vec<String>;
 
+#[cfg(feature = "build")]
+pub type RawEventObjectMembers = Vec<(String, String, RawParameters)>;
+
+#[cfg(feature = "build")]
+pub type RawEventObjectPages = Vec<(String, Url, RawEventObjectMembers)>;
+
 #[cfg(feature = "build")]
 pub type RawEventObjects = Map<String, Url>;
 
@@ -50,6 +56,9 @@ pub type RawGlobalAttributes = Vec<(String, RawGlobalAttributeDetails, String)>;
 
 pub type RawGlobalAttributeDetails = (BrowsersSupported, AttributeValues, RawCaveats);
 
+#[cfg(feature = "build")]
+pub type RawParameters = Vec<(String, String)>;
+
 pub type RawTag = (Description, bool, TagAlternatives);
 
 pub type RawTagDetails = (Option<SupportedBrowsers>, Option<TagAttributes>, Option<bool>, Option<bool>, Option<TagAttributes>);
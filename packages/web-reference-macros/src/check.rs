@@ -0,0 +1,40 @@
+use syn::{Error, LitStr};
+
+use web_reference::prelude::*;
+
+use crate::markup::{Node, StartTag};
+
+pub fn check_nodes(validator: &mut TagValidator, nodes: &[Node], errors: &mut Vec<Error>) {
+    for node in nodes {
+        if let Node::Element(element) = node {
+            check_start_tag(validator, &element.start_tag, element.self_closing, errors);
+            check_nodes(validator, &element.children, errors);
+
+            if !element.self_closing {
+                validator.end_tag(&element.start_tag.name.value);
+            }
+        }
+    }
+}
+
+// the validator's diagnostics of a start tag as errors at the tokens they are about
+pub fn check_start_tag(validator: &mut TagValidator, start_tag: &StartTag, self_closing: bool, errors: &mut Vec<Error>) {
+    let values = start_tag.attributes.iter()
+        .map(|attribute| attribute.value.as_ref().map(LitStr::value))
+        .collect::<Vec<_>>();
+
+    let attributes = start_tag.attributes.iter().zip(&values)
+        .map(|(attribute, value)| (attribute.name.value.as_str(), value.as_deref()))
+        .collect::<Vec<_>>();
+
+    for TagDiagnostic { target, message, .. } in validator.start_tag(&start_tag.name.value, &attributes, self_closing) {
+        let span = match target {
+            DiagnosticTarget::Tag => start_tag.name.span,
+            DiagnosticTarget::AttributeName(idx) => start_tag.attributes[idx].name.span,
+            DiagnosticTarget::AttributeValue(idx) => start_tag.attributes[idx].value.as_ref()
+                .map_or(start_tag.attributes[idx].name.span, LitStr::span),
+        };
+
+        errors.push(Error::new(span, message));
+    }
+}
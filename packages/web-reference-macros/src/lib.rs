@@ -0,0 +1,83 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+#![deny(clippy::cargo)]
+#![deny(missing_docs)]
+// ==============================================================
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::items_after_statements)]
+// syn 1 is still pulled in transitively by lazy-regex
+#![allow(clippy::multiple_crate_versions)]
+// ==============================================================
+
+#![doc = include_str ! ("../readme.md")]
+
+use proc_macro::TokenStream;
+
+use quote::quote;
+use syn::{Error, LitStr, parse_macro_input};
+
+use web_reference::prelude::{TagValidator, WebReference};
+
+use crate::check::{check_nodes, check_start_tag};
+use crate::markup::{Fragment, StartTag};
+use crate::render::{render_nodes, render_start_tag};
+
+mod check;
+mod markup;
+mod render;
+
+/// a compile time checked start tag, i.e. `element!(a href="/" target="_blank")`
+#[proc_macro]
+pub fn element(input: TokenStream) -> TokenStream {
+    let start_tag = parse_macro_input!(input as StartTag);
+    let mut errors = Vec::new();
+
+    check_start_tag(&mut TagValidator::new(WebReference::embedded()), &start_tag, true, &mut errors);
+
+    expand(errors, || {
+        let mut html = String::new();
+
+        render_start_tag(&mut html, &start_tag);
+
+        html
+    })
+}
+
+/// a compile time checked fragment of markup, i.e. `html! { <p class="note">"text"</p> }`
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    let fragment = parse_macro_input!(input as Fragment);
+    let mut errors = Vec::new();
+
+    check_nodes(&mut TagValidator::new(WebReference::embedded()), &fragment.nodes, &mut errors);
+
+    expand(errors, || {
+        let mut html = String::new();
+
+        render_nodes(&mut html, &fragment.nodes);
+
+        html
+    })
+}
+
+// expands to the rendered html as a string literal, or every error found in the markup
+fn expand(errors: Vec<Error>, render: impl FnOnce() -> String) -> TokenStream {
+    let errors = errors.into_iter()
+        .reduce(|mut combined, error| {
+            combined.combine(error);
+
+            combined
+        });
+
+    // the block keeps every error in expression position
+    if let Some(errors) = errors {
+        let errors = errors.to_compile_error();
+
+        return quote!({ #errors "" }).into();
+    }
+
+    let html = LitStr::new(&render(), proc_macro2::Span::call_site());
+
+    quote!(#html).into()
+}
@@ -0,0 +1,155 @@
+use proc_macro2::Span;
+use syn::{Ident, LitStr, Token};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+
+// a tag or attribute name, which can be hyphenated, i.e. `accept-charset` or `my-element`
+pub struct Name {
+    pub value: String,
+    pub span: Span,
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let first = Ident::parse_any(input)?;
+        let mut value = first.unraw().to_string();
+        let mut span = first.span();
+
+        while input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+
+            let next = Ident::parse_any(input)?;
+
+            value.push('-');
+            value.push_str(&next.unraw().to_string());
+            span = span.join(next.span()).unwrap_or(span);
+        }
+
+        Ok(Self { value, span })
+    }
+}
+
+pub struct Attribute {
+    pub name: Name,
+    pub value: Option<LitStr>,
+}
+
+impl Parse for Attribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { name, value })
+    }
+}
+
+// a tag name and its attributes, without the angle brackets
+pub struct StartTag {
+    pub name: Name,
+    pub attributes: Vec<Attribute>,
+}
+
+impl Parse for StartTag {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut attributes = Vec::new();
+
+        // attributes end with the input, or the closing bracket of an element
+        while !(input.is_empty() || input.peek(Token![>]) || input.peek(Token![/])) {
+            attributes.push(input.parse()?);
+        }
+
+        Ok(Self { name, attributes })
+    }
+}
+
+pub struct Element {
+    pub start_tag: StartTag,
+    pub self_closing: bool,
+    pub children: Vec<Node>,
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![<]>()?;
+
+        let start_tag = input.parse::<StartTag>()?;
+
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+
+            return Ok(Self { start_tag, self_closing: true, children: Vec::new() });
+        }
+
+        input.parse::<Token![>]>()?;
+
+        let mut children = Vec::new();
+
+        while !(input.peek(Token![<]) && input.peek2(Token![/])) {
+            if input.is_empty() {
+                return Err(syn::Error::new(
+                    start_tag.name.span,
+                    format!("missing closing tag </{}>", start_tag.name.value),
+                ));
+            }
+
+            children.push(input.parse()?);
+        }
+
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+
+        let end_tag = input.parse::<Name>()?;
+
+        if end_tag.value != start_tag.name.value {
+            return Err(syn::Error::new(
+                end_tag.span,
+                format!("expected closing tag </{}>, found </{}>", start_tag.name.value, end_tag.value),
+            ));
+        }
+
+        input.parse::<Token![>]>()?;
+
+        Ok(Self { start_tag, self_closing: false, children })
+    }
+}
+
+pub enum Node {
+    Element(Element),
+    Text(LitStr),
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ok(Self::Text(input.parse()?))
+        } else {
+            Ok(Self::Element(input.parse()?))
+        }
+    }
+}
+
+// a sequence of sibling nodes
+pub struct Fragment {
+    pub nodes: Vec<Node>,
+}
+
+impl Parse for Fragment {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut nodes = Vec::new();
+
+        while !input.is_empty() {
+            nodes.push(input.parse()?);
+        }
+
+        Ok(Self { nodes })
+    }
+}
@@ -0,0 +1,60 @@
+use crate::markup::{Node, StartTag};
+
+pub fn render_nodes(html: &mut String, nodes: &[Node]) {
+    for node in nodes {
+        match node {
+            Node::Element(element) => {
+                render_open_tag(html, &element.start_tag);
+
+                if element.self_closing {
+                    html.push_str("/>");
+                } else {
+                    html.push('>');
+
+                    render_nodes(html, &element.children);
+
+                    html.push_str("</");
+                    html.push_str(&element.start_tag.name.value);
+                    html.push('>');
+                }
+            }
+            Node::Text(text) =>
+                escape(html, &text.value(), &['&', '<', '>']),
+        }
+    }
+}
+
+pub fn render_start_tag(html: &mut String, start_tag: &StartTag) {
+    render_open_tag(html, start_tag);
+
+    html.push('>');
+}
+
+// renders a start tag up to, but not including, its closing bracket
+fn render_open_tag(html: &mut String, start_tag: &StartTag) {
+    html.push('<');
+    html.push_str(&start_tag.name.value);
+
+    for attribute in &start_tag.attributes {
+        html.push(' ');
+        html.push_str(&attribute.name.value);
+
+        if let Some(value) = &attribute.value {
+            html.push_str("=\"");
+            escape(html, &value.value(), &['&', '"']);
+            html.push('"');
+        }
+    }
+}
+
+fn escape(html: &mut String, text: &str, special: &[char]) {
+    for chr in text.chars() {
+        match chr {
+            '&' if special.contains(&chr) => html.push_str("&amp;"),
+            '<' if special.contains(&chr) => html.push_str("&lt;"),
+            '>' if special.contains(&chr) => html.push_str("&gt;"),
+            '"' if special.contains(&chr) => html.push_str("&quot;"),
+            chr => html.push(chr),
+        }
+    }
+}
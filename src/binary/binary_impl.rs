@@ -0,0 +1,21 @@
+use rkyv::rancor;
+
+use crate::prelude::*;
+
+impl WebReference {
+    /// the archived layout of the reference, see [`ReferenceView`] for lookups without deserializing it
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the reference fails to archive
+    pub fn to_binary(&self) -> Result<Vec<u8>, BinaryError> {
+        Ok(rkyv::to_bytes::<rancor::Error>(self)?.into_vec())
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the bytes are not an archived reference of the current [`FORMAT_VERSION`]
+    pub fn from_binary(binary: &[u8]) -> Result<Self, BinaryError> {
+        ReferenceView::new(binary)?.to_reference()
+    }
+}
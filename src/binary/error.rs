@@ -0,0 +1,42 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use rkyv::rancor;
+
+use crate::prelude::*;
+
+/// why a reference could not be archived to or read from its binary layout
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BinaryError {
+    /// binaries are not migrated like json, they are regenerated from json of the [`FORMAT_VERSION`]
+    UnsupportedVersion {
+        ///
+        version: u32,
+    },
+
+    /// the bytes are not an archived reference, i.e. they are json or truncated
+    InvalidData {
+        ///
+        reason: String,
+    },
+}
+
+impl Display for BinaryError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion { version } =>
+                write!(fmt, "binary format version {version} is not the supported {FORMAT_VERSION}"),
+            Self::InvalidData { reason } =>
+                write!(fmt, "invalid binary web reference, {reason}"),
+        }
+    }
+}
+
+impl Error for BinaryError {}
+
+impl From<rancor::Error> for BinaryError {
+    fn from(error: rancor::Error) -> Self {
+        Self::InvalidData { reason: error.to_string() }
+    }
+}
@@ -0,0 +1,5 @@
+//!
+
+pub mod binary_impl;
+pub mod error;
+pub mod view;
@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use rkyv::rancor;
+use rkyv::string::ArchivedString;
+
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+
+/// a view of the archived layout of a reference, its lookups borrow entries from the binary and
+/// those of [`ReferenceStore`] deserialize owned copies of only the entries they return
+#[derive(Copy, Clone)]
+pub struct ReferenceView<'a> {
+    reference: &'a ArchivedWebReference,
+}
+
+impl<'a> ReferenceView<'a> {
+    /// the binary is validated once, so lookups can not go out of bounds
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the bytes are not an archived reference of the current [`FORMAT_VERSION`]
+    pub fn new(binary: &'a [u8]) -> Result<Self, BinaryError> {
+        let reference = rkyv::access::<ArchivedWebReference, rancor::Error>(binary)?;
+        let version = reference.format_version.to_native();
+
+        if version == FORMAT_VERSION {
+            Ok(Self { reference })
+        } else {
+            Err(BinaryError::UnsupportedVersion { version })
+        }
+    }
+
+    /// the archived reference, for the sections the view has no lookups of
+    #[must_use]
+    pub const fn archived(&self) -> &'a ArchivedWebReference {
+        self.reference
+    }
+
+    /// an attribute of a tag
+    #[must_use]
+    pub fn attribute(&self, attribute: &str, tag: &str) -> Option<&'a ArchivedAttribute> {
+        self.reference.attributes.get(attribute)?.get(tag)
+    }
+
+    /// an event by its name, i.e. `onclick`
+    #[must_use]
+    pub fn event(&self, event: &str) -> Option<&'a ArchivedEvent> {
+        self.reference.events.get(event)
+    }
+
+    ///
+    #[must_use]
+    pub fn tag(&self, tag: &str) -> Option<&'a ArchivedTag> {
+        self.reference.tags.get(tag)
+    }
+
+    /// names of the attributes
+    pub fn attribute_names(&self) -> impl Iterator<Item=&'a str> {
+        self.reference.attributes.keys().map(ArchivedString::as_str)
+    }
+
+    /// names of the events
+    pub fn event_names(&self) -> impl Iterator<Item=&'a str> {
+        self.reference.events.keys().map(ArchivedString::as_str)
+    }
+
+    /// names of the tags
+    pub fn tag_names(&self) -> impl Iterator<Item=&'a str> {
+        self.reference.tags.keys().map(ArchivedString::as_str)
+    }
+
+    /// deserializes the whole reference
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an entry is not a valid model
+    pub fn to_reference(&self) -> Result<WebReference, BinaryError> {
+        deserialize(self.reference)
+    }
+}
+
+impl ReferenceStore for ReferenceView<'_> {
+    type Error = BinaryError;
+
+    fn is_valid_attribute(&self, attribute: &str) -> Result<bool, Self::Error> {
+        Ok(self.reference.attributes.contains_key(attribute))
+    }
+
+    fn is_valid_event(&self, event: &str) -> Result<bool, Self::Error> {
+        if event.starts_with(EVENT_PREFIX) {
+            Ok(self.reference.events.contains_key(event))
+        } else {
+            Ok(self.reference.events.contains_key(format!("{EVENT_PREFIX}{event}").as_str()))
+        }
+    }
+
+    fn is_valid_tag(&self, tag: &str) -> Result<bool, Self::Error> {
+        Ok(self.reference.tags.contains_key(tag))
+    }
+
+    fn get_attribute(&self, attribute: &str, tag: &str) -> Result<Option<Cow<'_, Attribute>>, Self::Error> {
+        self.attribute(attribute, tag).map(owned).transpose()
+    }
+
+    fn get_event(&self, event: &str) -> Result<Option<Cow<'_, Event>>, Self::Error> {
+        self.event(event).map(owned).transpose()
+    }
+
+    fn get_events_of_category(&self, category: EventCategory) -> Result<Vec<Cow<'_, Event>>, Self::Error> {
+        let Some((_, details)) = self.reference.events_categorized.iter().find(|(key, _)| **key == category) else {
+            return Ok(Vec::new());
+        };
+
+        details.events.iter()
+            .filter_map(|event| self.event(event.as_str()))
+            .map(owned)
+            .collect()
+    }
+
+    fn get_tag(&self, tag: &str) -> Result<Option<Cow<'_, Tag>>, Self::Error> {
+        self.tag(tag).map(owned).transpose()
+    }
+
+    fn get_tag_attributes(&self, tag: &str) -> Result<Vec<Cow<'_, Attribute>>, Self::Error> {
+        let Some(tag) = self.tag(tag) else {
+            return Ok(Vec::new());
+        };
+
+        tag.attributes.iter()
+            .chain(tag.optional_attributes.iter())
+            .filter_map(|attribute| self.attribute(attribute.as_str(), tag.name.as_str()))
+            .map(owned)
+            .collect()
+    }
+
+    fn get_tags_of_category(&self, category: TagCategory) -> Result<Vec<Cow<'_, Tag>>, Self::Error> {
+        let Some((_, details)) = self.reference.tags_categorized.iter().find(|(key, _)| **key == category) else {
+            return Ok(Vec::new());
+        };
+
+        details.tags.iter()
+            .filter_map(|tag| self.tag(tag.as_str()))
+            .map(owned)
+            .collect()
+    }
+}
+
+fn deserialize<T, A>(archived: &A) -> Result<T, BinaryError>
+    where A: rkyv::Deserialize<T, rancor::Strategy<rkyv::de::Pool, rancor::Error>>
+{
+    Ok(rkyv::deserialize::<T, rancor::Error>(archived)?)
+}
+
+fn owned<T: Clone, A>(archived: &A) -> Result<Cow<'static, T>, BinaryError>
+    where A: rkyv::Deserialize<T, rancor::Strategy<rkyv::de::Pool, rancor::Error>>
+{
+    deserialize(archived).map(Cow::Owned)
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+use std::fmt::Write;
+
+use crate::codegen::naming::{sorted, to_pascal_case, unique_identifiers};
+use crate::prelude::*;
+
+const GLOBAL: &str = "global";
+
+// a single enum variant, named after the html name it represents
+struct Variant<'a> {
+    name: &'a str,
+    ident: String,
+    description: Option<Description>,
+    deprecated: Option<String>,
+}
+
+impl WebReference {
+    /// generates rust source with an enum for tags, attributes, events, per tag attributes and attribute keywords
+    #[must_use]
+    pub fn generate_rust(&self) -> String {
+        let mut code = String::new();
+
+        self.write_rust(&mut code).map_or_else(|_| String::new(), |()| code)
+    }
+
+    /// writes the generated rust source into `out`, i.e. a file opened from a `build.rs`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `out` fails to write
+    pub fn write_rust<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out, "// generated by web-reference, do not edit")?;
+
+        self.write_tags(out)?;
+        self.write_attributes(out)?;
+        self.write_events(out)?;
+        self.write_tag_attributes(out)?;
+        self.write_attribute_values(out)
+    }
+
+    fn write_attributes<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let describe = |name: &str| Some(self.attributes.get(name)?.values().next()?.description.clone());
+
+        let names = sorted(self.attributes.keys().filter(|name| !name.contains('*')));
+
+        write_enum(out, "HtmlAttribute", &variants(names, describe, |_| None))?;
+
+        let global = self.get_attribute_category(AttributeCategory::GlobalAttributes)
+            .map(|global| sorted(global.attributes.iter().filter(|name| !name.contains('*'))))
+            .unwrap_or_default();
+
+        let describe = |name: &str| Some(self.attributes.get(name)?.get(GLOBAL)?.description.clone());
+
+        write_enum(out, "GlobalAttribute", &variants(global, describe, |_| None))
+    }
+
+    fn write_attribute_values<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for attribute in sorted(self.attributes.keys()) {
+            let tag_attributes = &self.attributes[attribute];
+
+            for tag in sorted(tag_attributes.keys()) {
+                let AttributeValue::Values(values) = &tag_attributes[tag].values else {
+                    continue;
+                };
+
+                // a single value describes the expected value rather than enumerating keywords
+                if values.len() < 2 {
+                    continue;
+                }
+
+                let describe = |name: &str| values.get(name).map(|value| value.description.clone());
+                let type_name = format!("{}{}Value", to_pascal_case(tag), to_pascal_case(attribute));
+
+                write_enum(out, &type_name, &variants(sorted(values.keys()), describe, |_| None))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_events<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let describe = |name: &str| Some(self.events.get(name)?.description.clone());
+        let deprecate = |name: &str| {
+            let event = self.events.get(name)?;
+
+            if event.deprecated.is_empty() {
+                None
+            } else {
+                Some(format!("use {} instead", sorted(event.deprecated.iter()).join(" or ")))
+            }
+        };
+
+        write_enum(out, "HtmlEvent", &variants(sorted(self.events.keys()), describe, deprecate))
+    }
+
+    fn write_tag_attributes<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for tag in sorted(self.tags.keys()) {
+            let details = &self.tags[tag];
+            let names = sorted(details.attributes.iter().chain(details.optional_attributes.iter()));
+
+            if names.is_empty() {
+                continue;
+            }
+
+            let describe = |name: &str| Some(self.get_tag_attribute(name, details)?.description.clone());
+
+            write_enum(out, &format!("{}TagAttribute", to_pascal_case(tag)), &variants(names, describe, |_| None))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_tags<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let describe = |name: &str| Some(self.tags.get(name)?.description.clone());
+        let deprecate = |name: &str| self.tags.get(name)?.alternatives.suggestion();
+
+        write_enum(out, "HtmlTag", &variants(sorted(self.tags.keys()), describe, deprecate))
+    }
+}
+
+fn variants(
+    names: Vec<&str>,
+    describe: impl Fn(&str) -> Option<Description>,
+    deprecate: impl Fn(&str) -> Option<String>,
+) -> Vec<Variant<'_>> {
+    unique_identifiers(names).into_iter()
+        .map(|(name, ident)| Variant {
+            name,
+            ident,
+            description: describe(name).filter(|description| !description.is_empty()),
+            deprecated: deprecate(name),
+        })
+        .collect()
+}
+
+fn write_enum<W: Write>(out: &mut W, type_name: &str, variants: &[Variant]) -> fmt::Result {
+    writeln!(out)?;
+    writeln!(out, "#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]")?;
+    writeln!(out, "pub enum {type_name} {{")?;
+
+    for variant in variants {
+        if let Some(description) = &variant.description {
+            for line in description.as_ref().lines().map(str::trim).filter(|line| !line.is_empty()) {
+                writeln!(out, "    /// {line}")?;
+            }
+        }
+
+        if let Some(note) = &variant.deprecated {
+            writeln!(out, "    #[deprecated(note = {note:?})]")?;
+        }
+
+        writeln!(out, "    {},", variant.ident)?;
+    }
+
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(deprecated)]")?;
+    writeln!(out, "impl {type_name} {{")?;
+    writeln!(out, "    pub const ALL: &'static [Self] = &[")?;
+
+    for variant in variants {
+        writeln!(out, "        Self::{},", variant.ident)?;
+    }
+
+    writeln!(out, "    ];")?;
+    writeln!(out)?;
+    writeln!(out, "    #[must_use]")?;
+    writeln!(out, "    pub const fn name(self) -> &'static str {{")?;
+    writeln!(out, "        match self {{")?;
+
+    for variant in variants {
+        writeln!(out, "            Self::{} => {:?},", variant.ident, variant.name)?;
+    }
+
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(deprecated)]")?;
+    writeln!(out, "impl ::std::str::FromStr for {type_name} {{")?;
+    writeln!(out, "    type Err = ::std::string::String;")?;
+    writeln!(out)?;
+    writeln!(out, "    fn from_str(src: &str) -> ::std::result::Result<Self, Self::Err> {{")?;
+    writeln!(out, "        match src {{")?;
+
+    for variant in variants {
+        writeln!(out, "            {:?} => ::std::result::Result::Ok(Self::{}),", variant.name, variant.ident)?;
+    }
+
+    writeln!(out, "            unexpected => ::std::result::Result::Err(::std::format!(\"{{unexpected:?}} is not a valid {type_name}\")),")?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "impl ::std::fmt::Display for {type_name} {{")?;
+    writeln!(out, "    fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{")?;
+    writeln!(out, "        fmt.write_str(self.name())")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")
+}
@@ -0,0 +1,5 @@
+//!
+
+pub mod codegen_impl;
+pub mod naming;
+pub mod typescript_impl;
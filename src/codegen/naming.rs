@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+// identifiers a variant cannot take, `Err` is the error type of the generated `FromStr` impls
+const RESERVED: [&str; 2] = ["Self", "Err"];
+
+/// converts an html name into a rust type or variant identifier, i.e. `accept-charset` to `AcceptCharset`
+#[must_use]
+pub fn to_pascal_case(name: &str) -> String {
+    let mut ident = name.split(|chr: char| !chr.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+
+            chars.next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    if ident.is_empty() || ident.starts_with(|chr: char| chr.is_ascii_digit()) {
+        ident.insert(0, 'V');
+    }
+
+    ident
+}
+
+/// names in order, without duplicates
+#[must_use]
+pub fn sorted<'a>(names: impl Iterator<Item=&'a String>) -> Vec<&'a str> {
+    let mut names = names.map(String::as_str).collect::<Vec<_>>();
+
+    names.sort_unstable();
+    names.dedup();
+
+    names
+}
+
+/// pairs each name with a unique identifier, appending a counter to colliding identifiers
+#[must_use]
+pub fn unique_identifiers<'a>(names: impl IntoIterator<Item=&'a str>) -> Vec<(&'a str, String)> {
+    let mut seen = Set::<String>::new();
+
+    names.into_iter()
+        .map(|name| {
+            let mut ident = to_pascal_case(name);
+
+            if RESERVED.contains(&ident.as_str()) {
+                ident.push('_');
+            }
+
+            let mut unique = ident.clone();
+            let mut counter = 1;
+
+            while seen.contains(&unique) {
+                counter += 1;
+                unique = format!("{ident}{counter}");
+            }
+
+            seen.insert(unique.clone());
+
+            (name, unique)
+        })
+        .collect()
+}
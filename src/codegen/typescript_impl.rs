@@ -0,0 +1,207 @@
+use std::fmt;
+use std::fmt::Write;
+
+use crate::codegen::naming::{sorted, unique_identifiers};
+use crate::prelude::*;
+
+const GLOBAL: &str = "global";
+const DATA_ATTRIBUTE: &str = "data-*";
+
+const EVENT_HANDLERS: &str = "HtmlEventHandlers";
+const GLOBAL_ATTRIBUTES: &str = "GlobalAttributes";
+
+impl WebReference {
+    /// generates typescript declarations of `JSX.IntrinsicElements`, with the attributes, keywords and
+    /// event handlers of each tag as the validator accepts them
+    #[must_use]
+    pub fn generate_typescript(&self) -> String {
+        let mut code = String::new();
+
+        self.write_typescript(&mut code).map_or_else(|_| String::new(), |()| code)
+    }
+
+    /// writes the generated typescript declarations into `out`, i.e. a `.d.ts` file
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `out` fails to write
+    pub fn write_typescript<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out, "// generated by web-reference, do not edit")?;
+
+        self.write_event_handlers(out)?;
+        self.write_global_attributes(out)?;
+
+        let tags = unique_identifiers(sorted(self.tags.keys())).into_iter()
+            .map(|(tag, ident)| (tag, format!("{ident}TagAttributes")))
+            .collect::<Vec<_>>();
+
+        for (tag, interface) in &tags {
+            self.write_tag_attributes_interface(out, &self.tags[*tag], interface)?;
+        }
+
+        writeln!(out)?;
+        writeln!(out, "declare global {{")?;
+        writeln!(out, "    namespace JSX {{")?;
+        writeln!(out, "        interface IntrinsicElements {{")?;
+
+        for (tag, interface) in &tags {
+            let details = &self.tags[*tag];
+
+            write_doc(out, "            ", &details.description, details.alternatives.suggestion().as_deref())?;
+            writeln!(out, "            {}: {interface};", property_name(tag))?;
+        }
+
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")
+    }
+
+    // every event is valid on every tag, unless an attribute of the same name shadows it
+    fn write_event_handlers<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "export interface {EVENT_HANDLERS} {{")?;
+
+        for name in sorted(self.events.keys()).into_iter().filter(|name| !self.attributes.contains_key(*name)) {
+            let event = &self.events[name];
+
+            let objects = if event.event_objects.is_empty() {
+                String::from("Event")
+            } else {
+                sorted(event.event_objects.iter()).join(" | ")
+            };
+
+            let deprecated = (!event.deprecated.is_empty())
+                .then(|| format!("use {} instead", sorted(event.deprecated.iter()).join(" or ")));
+
+            write_doc(out, "    ", &event.description, deprecated.as_deref())?;
+            writeln!(out, "    {}?: (event: {objects}) => void;", property_name(name))?;
+        }
+
+        writeln!(out, "}}")
+    }
+
+    fn write_global_attributes<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out)?;
+        writeln!(out, "export interface {GLOBAL_ATTRIBUTES} extends {EVENT_HANDLERS} {{")?;
+
+        for name in self.global_attribute_names() {
+            if let Some(attribute) = self.attributes[name].get(GLOBAL) {
+                write_property(out, name, attribute)?;
+            }
+        }
+
+        if self.attributes.contains_key(DATA_ATTRIBUTE) {
+            writeln!(out, "    [attribute: `data-${{string}}`]: string;")?;
+        }
+
+        writeln!(out, "}}")
+    }
+
+    fn write_tag_attributes_interface<W: Write>(&self, out: &mut W, tag: &Tag, interface: &str) -> fmt::Result {
+        let names = sorted(tag.attributes.iter().chain(tag.optional_attributes.iter()).filter(|name| !name.contains('*')));
+
+        let extends = if tag.global_attributes {
+            // tag specific definitions replace the global ones of the same name
+            let globals = self.global_attribute_names();
+            let shadowed = names.iter()
+                .filter(|name| globals.contains(name))
+                .map(|name| format!("{name:?}"))
+                .collect::<Vec<_>>();
+
+            if shadowed.is_empty() {
+                String::from(GLOBAL_ATTRIBUTES)
+            } else {
+                format!("Omit<{GLOBAL_ATTRIBUTES}, {}>", shadowed.join(" | "))
+            }
+        } else {
+            String::from(EVENT_HANDLERS)
+        };
+
+        writeln!(out)?;
+        writeln!(out, "export interface {interface} extends {extends} {{")?;
+
+        for name in names {
+            let attribute = self.get_tag_attribute(name, tag)
+                .or_else(|| self.attributes.get(name)?.get(GLOBAL));
+
+            match attribute {
+                Some(attribute) => write_property(out, name, attribute)?,
+                None => writeln!(out, "    {}?: string;", property_name(name))?,
+            }
+        }
+
+        writeln!(out, "}}")
+    }
+
+    fn global_attribute_names(&self) -> Vec<&str> {
+        self.get_attribute_category(AttributeCategory::GlobalAttributes)
+            .map(|global| sorted(global.attributes.iter().filter(|name| !name.contains('*'))))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|name| self.attributes.contains_key(*name))
+            .collect()
+    }
+}
+
+// the keywords of an attribute as a union of string literals, otherwise the values the validator accepts
+fn typescript_type(name: &str, values: &AttributeValue) -> String {
+    if let Some(mut keywords) = values.keywords(name) {
+        keywords.sort_unstable();
+        keywords.dedup();
+
+        return keywords.iter().map(|keyword| format!("{keyword:?}")).collect::<Vec<_>>().join(" | ");
+    }
+
+    String::from(match values {
+        AttributeValue::Boolean { .. } => "boolean",
+        AttributeValue::Number { .. } => "number | `${number}`",
+        AttributeValue::Pixels { .. } => "number | `${number}` | `${number}px`",
+        _ => "string",
+    })
+}
+
+fn property_name(name: &str) -> String {
+    let is_identifier = name.starts_with(|chr: char| chr.is_ascii_alphabetic() || chr == '_' || chr == '$') &&
+        name.chars().all(|chr| chr.is_ascii_alphanumeric() || chr == '_' || chr == '$');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("{name:?}")
+    }
+}
+
+fn write_property<W: Write>(out: &mut W, name: &str, attribute: &Attribute) -> fmt::Result {
+    write_doc(out, "    ", &attribute.description, None)?;
+    writeln!(out, "    {}?: {};", property_name(name), typescript_type(name, &attribute.values))
+}
+
+fn write_doc<W: Write>(out: &mut W, indent: &str, description: &Description, deprecated: Option<&str>) -> fmt::Result {
+    let lines = description.as_ref().lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect::<Vec<_>>();
+
+    match (lines.as_slice(), deprecated) {
+        ([], None) => Ok(()),
+        ([line], None) => writeln!(out, "{indent}/** {line} */"),
+        (lines, deprecated) => {
+            writeln!(out, "{indent}/**")?;
+
+            for line in lines {
+                writeln!(out, "{indent} * {line}")?;
+            }
+
+            if let Some(deprecated) = deprecated {
+                if !lines.is_empty() {
+                    writeln!(out, "{indent} *")?;
+                }
+
+                writeln!(out, "{indent} * @deprecated {}", deprecated.replace("*/", "*\\/"))?;
+            }
+
+            writeln!(out, "{indent} */")
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt;
+use std::fmt::{Display, Formatter, Write};
+
+use crate::prelude::*;
+
+/// the changes between two references, see [`WebReference::diff`]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Changelog {
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "EntityChanges::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub tags: EntityChanges,
+
+    /// attributes by their name and the tag they are defined for, `global` for global attributes
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "EntityChanges::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub attributes: EntityChanges,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "EntityChanges::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub events: EntityChanges,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "EntityChanges::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub svg_tags: EntityChanges,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "EntityChanges::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub svg_attributes: EntityChanges,
+
+    /// entities of both references whose categories changed
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub categories: Vec<CategoryChange>,
+}
+
+impl Changelog {
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sections().iter().all(|(_, changes)| changes.is_empty()) && self.categories.is_empty()
+    }
+
+    /// renders the changelog as markdown, i.e. to print after regenerating a reference
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+
+        self.write_markdown(&mut markdown).map_or_else(|_| String::new(), |()| markdown)
+    }
+
+    /// writes the changelog as markdown into `out`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `out` fails to write
+    pub fn write_markdown<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out, "# Changelog")?;
+
+        if self.is_empty() {
+            return writeln!(out, "\nNo changes");
+        }
+
+        for (title, changes) in self.sections() {
+            if !changes.is_empty() {
+                writeln!(out, "\n## {title}")?;
+
+                changes.write_markdown(out)?;
+            }
+        }
+
+        if !self.categories.is_empty() {
+            writeln!(out, "\n## Categories\n")?;
+
+            for change in &self.categories {
+                writeln!(out, "* {change}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// renders the changelog as pretty printed json
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the changelog fails to serialize
+    #[cfg(feature = "serde_json")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    const fn sections(&self) -> [(&'static str, &EntityChanges); 5] {
+        [
+            ("Tags", &self.tags),
+            ("Attributes", &self.attributes),
+            ("Events", &self.events),
+            ("SVG Tags", &self.svg_tags),
+            ("SVG Attributes", &self.svg_attributes),
+        ]
+    }
+}
+
+/// the entities of one kind that were added, removed or changed
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct EntityChanges {
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub added: Vec<EntityName>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub removed: Vec<EntityName>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub changed: Vec<EntityChange>,
+}
+
+impl EntityChanges {
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn write_markdown<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for (title, names) in [("Added", &self.added), ("Removed", &self.removed)] {
+            if !names.is_empty() {
+                writeln!(out, "\n### {title}\n")?;
+
+                for name in names {
+                    writeln!(out, "* {name}")?;
+                }
+            }
+        }
+
+        if !self.changed.is_empty() {
+            writeln!(out, "\n### Changed\n")?;
+
+            for change in &self.changed {
+                change.write_markdown(out)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// the name of an entity, with the tag it is defined for if it is an attribute
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EntityName {
+    ///
+    pub name: String,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub tag: Option<String>,
+}
+
+impl Display for EntityName {
+    // i.e. "`href` of `a`"
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "`{}`", self.name)?;
+
+        if let Some(tag) = &self.tag {
+            write!(fmt, " of `{tag}`")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// the changes of an entity found in both references
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EntityChange {
+    ///
+    pub entity: EntityName,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Option<DescriptionChange>,
+
+    /// the browsers whose support changed
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub support: Vec<SupportChange>,
+
+    /// the keywords an attribute gained, i.e. a new `type` of `<input>`
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub added_values: Vec<String>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub removed_values: Vec<String>,
+}
+
+impl EntityChange {
+    ///
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.description.is_none() && self.support.is_empty() && self.added_values.is_empty() && self.removed_values.is_empty()
+    }
+
+    fn write_markdown<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out, "* {}", self.entity)?;
+
+        if let Some(DescriptionChange { before, after }) = &self.description {
+            writeln!(out, "  * description: \"{before}\" → \"{after}\"")?;
+        }
+
+        for SupportChange { browser, before, after } in &self.support {
+            let before = before.as_ref().map_or_else(|| String::from("none"), ToString::to_string);
+            let after = after.as_ref().map_or_else(|| String::from("none"), ToString::to_string);
+
+            writeln!(out, "  * {browser}: {before} → {after}")?;
+        }
+
+        for (title, values) in [("added values", &self.added_values), ("removed values", &self.removed_values)] {
+            if !values.is_empty() {
+                let values = values.iter().map(|value| format!("`{value}`")).collect::<Vec<_>>();
+
+                writeln!(out, "  * {title}: {}", values.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DescriptionChange {
+    ///
+    pub before: Description,
+
+    ///
+    pub after: Description,
+}
+
+/// the support of a browser before and after, none when the reference does not mention the browser
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SupportChange {
+    ///
+    pub browser: Browser,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub before: Option<Support>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub after: Option<Support>,
+}
+
+/// an entity that moved between categories, i.e. an attribute that became global
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CategoryChange {
+    /// `tag`, `attribute`, `event` or `svg-attribute`
+    pub kind: String,
+
+    ///
+    pub name: String,
+
+    /// the categories by their names in the reference's json, i.e. `global-attributes`
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub removed_from: Vec<String>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Vec::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub added_to: Vec<String>,
+}
+
+impl Display for CategoryChange {
+    // i.e. "`menu` tag, removed from `lists`, added to `basic`"
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "`{}` {}", self.name, self.kind)?;
+
+        for (title, categories) in [("removed from", &self.removed_from), ("added to", &self.added_to)] {
+            if !categories.is_empty() {
+                let categories = categories.iter().map(|category| format!("`{category}`")).collect::<Vec<_>>();
+
+                write!(fmt, ", {title} {}", categories.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt::Debug;
+
+use crate::prelude::*;
+
+// the parts of tags, attributes and events that a changelog compares
+trait Entity {
+    fn description(&self) -> &Description;
+
+    fn supported(&self) -> &BrowsersSupported;
+
+    fn values(&self) -> Option<&AttributeValues> {
+        None
+    }
+}
+
+impl Entity for Tag {
+    fn description(&self) -> &Description {
+        &self.description
+    }
+
+    fn supported(&self) -> &BrowsersSupported {
+        &self.supported.supported
+    }
+}
+
+impl Entity for Attribute {
+    fn description(&self) -> &Description {
+        &self.description
+    }
+
+    fn supported(&self) -> &BrowsersSupported {
+        &self.supported.supported
+    }
+
+    fn values(&self) -> Option<&AttributeValues> {
+        match &self.values {
+            AttributeValue::Values(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl Entity for Event {
+    fn description(&self) -> &Description {
+        &self.description
+    }
+
+    fn supported(&self) -> &BrowsersSupported {
+        &self.supported.supported
+    }
+}
+
+impl WebReference {
+    /// the changes from this reference to a newer one, i.e. after the factory regenerated `html-5-specs.json`
+    #[must_use]
+    pub fn diff(&self, newer: &Self) -> Changelog {
+        let tags = |reference: &Self| category_members(&reference.tags_categorized, |details| &details.tags);
+        let attributes = |reference: &Self| category_members(&reference.attributes_categorized, |details| &details.attributes);
+        let events = |reference: &Self| category_members(&reference.events_categorized, |details| &details.events);
+        let svg_attributes = |reference: &Self| category_members(&reference.svg_attributes_categorized, |details| &details.attributes);
+
+        let categories = [
+            diff_categories("tag", &self.tags, &tags(self), &newer.tags, &tags(newer)),
+            diff_categories("attribute", &self.attributes, &attributes(self), &newer.attributes, &attributes(newer)),
+            diff_categories("event", &self.events, &events(self), &newer.events, &events(newer)),
+            diff_categories("svg-attribute", &self.svg_attributes, &svg_attributes(self), &newer.svg_attributes, &svg_attributes(newer)),
+        ];
+
+        Changelog {
+            tags: diff_entities(&named(&self.tags), &named(&newer.tags)),
+            attributes: diff_entities(&attributes_named(&self.attributes), &attributes_named(&newer.attributes)),
+            events: diff_entities(&named(&self.events), &named(&newer.events)),
+            svg_tags: diff_entities(&named(&self.svg_tags), &named(&newer.svg_tags)),
+            svg_attributes: diff_entities(&attributes_named(&self.svg_attributes), &attributes_named(&newer.svg_attributes)),
+            categories: categories.into_iter().flatten().collect(),
+        }
+    }
+}
+
+fn named<T>(entities: &Map<String, T>) -> Map<EntityName, &T> {
+    entities.iter()
+        .map(|(name, entity)| (EntityName { name: name.clone(), tag: None }, entity))
+        .collect()
+}
+
+fn attributes_named(attributes: &Attributes) -> Map<EntityName, &Attribute> {
+    attributes.iter()
+        .flat_map(|(name, tags)| tags.iter().map(move |(tag, attribute)| (EntityName { name: name.clone(), tag: Some(tag.clone()) }, attribute)))
+        .collect()
+}
+
+fn diff_entities<T: Entity>(before: &Map<EntityName, &T>, after: &Map<EntityName, &T>) -> EntityChanges {
+    let mut added = after.keys().filter(|name| !before.contains_key(*name)).cloned().collect::<Vec<_>>();
+    let mut removed = before.keys().filter(|name| !after.contains_key(*name)).cloned().collect::<Vec<_>>();
+
+    let mut changed = after.iter()
+        .filter_map(|(name, after)| before.get(name).map(|before| diff_entity(name, *before, *after)))
+        .filter(|change| !change.is_empty())
+        .collect::<Vec<_>>();
+
+    added.sort();
+    removed.sort();
+    changed.sort_by(|a, b| a.entity.cmp(&b.entity));
+
+    EntityChanges { added, removed, changed }
+}
+
+fn diff_entity<T: Entity>(name: &EntityName, before: &T, after: &T) -> EntityChange {
+    let description = (before.description() != after.description()).then(|| DescriptionChange {
+        before: before.description().clone(),
+        after: after.description().clone(),
+    });
+
+    let mut browsers = before.supported().keys().chain(after.supported().keys()).copied().collect::<Vec<_>>();
+
+    browsers.sort();
+    browsers.dedup();
+
+    let support = browsers.into_iter()
+        .map(|browser| SupportChange {
+            browser,
+            before: before.supported().get(&browser).cloned(),
+            after: after.supported().get(&browser).cloned(),
+        })
+        .filter(|change| change.before != change.after)
+        .collect();
+
+    let values = |values: Option<&AttributeValues>| values.map(|values| values.keys().cloned().collect::<Vec<_>>()).unwrap_or_default();
+    let (before_values, after_values) = (values(before.values()), values(after.values()));
+
+    EntityChange {
+        entity: name.clone(),
+        description,
+        support,
+        added_values: sorted_difference(&after_values, &before_values),
+        removed_values: sorted_difference(&before_values, &after_values),
+    }
+}
+
+// the names of the members of each category, by the category's name in the reference's json
+fn category_members<C: Debug, D>(categorized: &Map<C, D>, members: impl Fn(&D) -> &Set<String>) -> Vec<(String, Vec<String>)> {
+    categorized.iter()
+        .map(|(category, details)| (serialized_name(category), members(details).iter().cloned().collect()))
+        .collect()
+}
+
+// the category changes of the entities found in both references
+fn diff_categories<T>(
+    kind: &str,
+    before_entities: &Map<String, T>, before: &[(String, Vec<String>)],
+    after_entities: &Map<String, T>, after: &[(String, Vec<String>)],
+) -> Vec<CategoryChange> {
+    let categories_of = |categorized: &[(String, Vec<String>)], name: &str| categorized.iter()
+        .filter(|(_, members)| members.iter().any(|member| member == name))
+        .map(|(category, _)| category.clone())
+        .collect::<Vec<_>>();
+
+    let mut names = after_entities.keys().filter(|name| before_entities.contains_key(*name)).collect::<Vec<_>>();
+
+    names.sort();
+
+    names.into_iter()
+        .map(|name| {
+            let (before, after) = (categories_of(before, name), categories_of(after, name));
+
+            CategoryChange {
+                kind: kind.to_string(),
+                name: name.clone(),
+                removed_from: sorted_difference(&before, &after),
+                added_to: sorted_difference(&after, &before),
+            }
+        })
+        .filter(|change| !change.removed_from.is_empty() || !change.added_to.is_empty())
+        .collect()
+}
+
+fn sorted_difference<T: Clone + Ord>(of: &[T], without: &[T]) -> Vec<T> {
+    let mut difference = of.iter().filter(|item| !without.contains(item)).cloned().collect::<Vec<_>>();
+
+    difference.sort();
+    difference.dedup();
+
+    difference
+}
+
+// categories are named as they are serialized in kebab case, i.e. `global-attributes`
+fn serialized_name(category: impl Debug) -> String {
+    format!("{category:?}").chars()
+        .enumerate()
+        .fold(String::new(), |mut name, (index, chr)| {
+            if chr.is_ascii_uppercase() && index > 0 {
+                name.push('-');
+            }
+
+            name.push(chr.to_ascii_lowercase());
+
+            name
+        })
+}
@@ -0,0 +1,4 @@
+//!
+
+pub mod changelog;
+pub mod diff_impl;
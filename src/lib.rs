@@ -6,6 +6,9 @@
 // ==============================================================
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::items_after_statements)]
+// indexmap and rkyv of `ordered-map` and `binary` and the hashlink of rusqlite depend on different hashbrown
+// versions, syn 1 is still pulled in transitively by lazy-regex and syn 3 by the derives of rkyv
+#![allow(clippy::multiple_crate_versions)]
 // ==============================================================
 #![doc(html_root_url = "https://docs.rs/web-reference/0.1.0")]
 
@@ -20,7 +23,16 @@ extern crate lazy_regex;
 #[macro_use]
 extern crate serde;
 
+#[cfg(feature = "binary")]
+mod binary;
+#[cfg(feature = "codegen")]
+mod codegen;
+#[cfg(feature = "diff")]
+mod diff;
 mod models;
+mod store;
+#[cfg(feature = "validate")]
+mod validate;
 
 pub mod prelude;
 
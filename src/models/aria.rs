@@ -0,0 +1,230 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AriaRole {
+    ///
+    pub name: String,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Description::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Description,
+
+    /// abstract roles, i.e. `widget`, only organize the taxonomy and are not to be used in documents
+    #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename = "abstract"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub is_abstract: bool,
+
+    /// roles the role inherits its supported states and properties from, i.e. `input` of `checkbox`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub superclasses: SuperclassRoles,
+
+    ///
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub required_attributes: HasAttributes,
+
+    /// states and properties the role supports besides the required and global ones
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub supported_attributes: HasAttributes,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AriaAttribute {
+    ///
+    pub name: String,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Description::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Description,
+
+    ///
+    pub kind: AriaAttributeKind,
+
+    ///
+    pub value_type: AriaValueType,
+
+    /// the values of `token` and `token-list` types, i.e. `polite` of `aria-live`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub tokens: AriaTokens,
+
+    /// global states and properties apply to every role
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub global: bool,
+
+    ///
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub deprecated: bool,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AriaAttributeKind {
+    /// a characteristic likely to change, i.e. `aria-expanded`
+    State,
+
+    /// a characteristic less likely to change, i.e. `aria-label`
+    Property,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AriaValueType {
+    ///
+    TrueFalse,
+
+    ///
+    TrueFalseUndefined,
+
+    /// `true`, `false` or `mixed`
+    Tristate,
+
+    ///
+    IdReference,
+
+    ///
+    IdReferenceList,
+
+    ///
+    Integer,
+
+    ///
+    Number,
+
+    ///
+    String,
+
+    /// one of the attribute's tokens
+    Token,
+
+    /// a space separated list of the attribute's tokens
+    TokenList,
+}
+
+impl Display for AriaAttributeKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::State => "state",
+            Self::Property => "property",
+        })
+    }
+}
+
+impl Display for AriaValueType {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::TrueFalse => "true/false",
+            Self::TrueFalseUndefined => "true/false/undefined",
+            Self::Tristate => "tristate",
+            Self::IdReference => "id reference",
+            Self::IdReferenceList => "id reference list",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Token => "token",
+            Self::TokenList => "token list",
+        })
+    }
+}
+
+impl AriaValueType {
+    /// the values a type allows regardless of the attribute, i.e. `mixed` of `tristate`
+    #[must_use]
+    pub const fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Self::TrueFalse => &["true", "false"],
+            Self::TrueFalseUndefined => &["true", "false", "undefined"],
+            Self::Tristate => &["true", "false", "mixed"],
+            _ => &[],
+        }
+    }
+}
+
+/// the roles an html tag allows in its `role` attribute
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub enum AllowedAriaRoles {
+    ///
+    #[default]
+    Any,
+
+    ///
+    None,
+
+    ///
+    Roles(
+        #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+        #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+        #[cfg_attr(feature = "deserialize", serde(default))]
+        HasAriaRoles
+    ),
+}
+
+impl AllowedAriaRoles {
+    ///
+    #[must_use]
+    pub fn allows(&self, role: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::None => false,
+            Self::Roles(roles) => roles.contains(role),
+        }
+    }
+}
+
+/// the accessibility semantics of an html tag
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TagAria {
+    /// the role of the tag without a `role` attribute, i.e. `list` of `<ul>`
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub implicit_role: Option<String>,
+
+    ///
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub allowed_roles: AllowedAriaRoles,
+}
+
+impl TagAria {
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.implicit_role.is_none() && self.allowed_roles == AllowedAriaRoles::Any
+    }
+}
@@ -1,8 +1,12 @@
+// rkyv leaves the fields of the resolvers it derives for enum variants with fields undocumented
+#![cfg_attr(feature = "binary", allow(missing_docs))]
+
 use crate::prelude::*;
 
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Attribute {
@@ -29,6 +33,7 @@ pub struct Attribute {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct AttributeCategoryDetails {
@@ -37,7 +42,6 @@ pub struct AttributeCategoryDetails {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub attributes: HasAttributes,
@@ -46,6 +50,7 @@ pub struct AttributeCategoryDetails {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AttributeValue {
@@ -169,7 +174,7 @@ pub enum AttributeValue {
     ///
     Values(
         #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-        #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+        #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
         #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
         #[cfg_attr(feature = "deserialize", serde(default))]
         AttributeValues
@@ -255,9 +260,103 @@ impl TryFrom<AttributeValues> for AttributeValue {
     }
 }
 
+impl AttributeValue {
+    /// the description of a typed value, none for enumerated values
+    #[must_use]
+    pub const fn description(&self) -> Option<&Description> {
+        match self {
+            Self::Boolean { description } |
+            Self::BooleanAuto { description } |
+            Self::CharacterSet { description } |
+            Self::DateTime { description } |
+            Self::Filename { description } |
+            Self::HTMLCode { description } |
+            Self::LanguageCode { description } |
+            Self::Id { description } |
+            Self::MapName { description } |
+            Self::MediaType { description } |
+            Self::MediaQuery { description } |
+            Self::Number { description } |
+            Self::OnOff { description } |
+            Self::Pixels { description } |
+            Self::RegExp { description } |
+            Self::Style { description } |
+            Self::Text { description } |
+            Self::URL { description } |
+            Self::URLList { description } |
+            Self::YesNo { description } =>
+                Some(description),
+            Self::None | Self::Values(_) =>
+                None
+        }
+    }
+
+    /// the name of the kind of value, i.e. `Boolean`
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Boolean { .. } => "Boolean",
+            Self::BooleanAuto { .. } => "BooleanAuto",
+            Self::CharacterSet { .. } => "CharacterSet",
+            Self::DateTime { .. } => "DateTime",
+            Self::Filename { .. } => "Filename",
+            Self::HTMLCode { .. } => "HTMLCode",
+            Self::LanguageCode { .. } => "LanguageCode",
+            Self::Id { .. } => "Id",
+            Self::MapName { .. } => "MapName",
+            Self::MediaType { .. } => "MediaType",
+            Self::MediaQuery { .. } => "MediaQuery",
+            Self::Number { .. } => "Number",
+            Self::OnOff { .. } => "OnOff",
+            Self::Pixels { .. } => "Pixels",
+            Self::RegExp { .. } => "RegExp",
+            Self::Style { .. } => "Style",
+            Self::Text { .. } => "Text",
+            Self::URL { .. } => "URL",
+            Self::URLList { .. } => "URLList",
+            Self::YesNo { .. } => "YesNo",
+            Self::Values(_) => "Values",
+        }
+    }
+
+    /// a typed value from the name of its kind, the inverse of [`AttributeValue::kind`]
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `kind` is not the name of a typed value
+    pub fn from_kind(kind: &str, description: Description) -> Result<Self, String> {
+        Ok(match kind {
+            "None" => Self::None,
+            "Boolean" => Self::Boolean { description },
+            "BooleanAuto" => Self::BooleanAuto { description },
+            "CharacterSet" => Self::CharacterSet { description },
+            "DateTime" => Self::DateTime { description },
+            "Filename" => Self::Filename { description },
+            "HTMLCode" => Self::HTMLCode { description },
+            "LanguageCode" => Self::LanguageCode { description },
+            "Id" => Self::Id { description },
+            "MapName" => Self::MapName { description },
+            "MediaType" => Self::MediaType { description },
+            "MediaQuery" => Self::MediaQuery { description },
+            "Number" => Self::Number { description },
+            "OnOff" => Self::OnOff { description },
+            "Pixels" => Self::Pixels { description },
+            "RegExp" => Self::RegExp { description },
+            "Style" => Self::Style { description },
+            "Text" => Self::Text { description },
+            "URL" => Self::URL { description },
+            "URLList" => Self::URLList { description },
+            "YesNo" => Self::YesNo { description },
+            unexpected => return Err(format!("{unexpected:?} is not a valid attribute value kind"))
+        })
+    }
+}
+
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 // todo: untagged generates null values for simple named variants
 // #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case", untagged))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
@@ -266,7 +365,6 @@ pub enum AttributeBelongsTo {
     ///
     Tags(
         #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-        #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
         #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
         #[cfg_attr(feature = "deserialize", serde(default))]
         BelongsToTags
@@ -291,6 +389,7 @@ impl AttributeBelongsTo {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum AttributeCategory {
@@ -302,6 +401,9 @@ pub enum AttributeCategory {
 
     ///
     NotSupported,
+
+    /// svg attributes that set the css property of the same name, i.e. `fill`
+    PresentationAttributes,
 }
 
 impl From<AttributeBelongsTo> for AttributeCategory {
@@ -320,3 +422,22 @@ impl<'a> From<&'a AttributeBelongsTo> for AttributeCategory {
         }
     }
 }
+
+impl TryFrom<&str> for AttributeCategory {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "global" | "globalattributes" | "global-attributes" =>
+                Self::GlobalAttributes,
+            "specific" | "tags" | "specifictotags" | "specific-to-tags" =>
+                Self::SpecificToTags,
+            "obsolete" | "notsupported" | "not-supported" =>
+                Self::NotSupported,
+            "presentation" | "presentationattributes" | "presentation-attributes" =>
+                Self::PresentationAttributes,
+            unexpected =>
+                return Err(format!("{unexpected:?} is not a valid attribute category"))
+        })
+    }
+}
@@ -0,0 +1,275 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+// attributes whose value is a set of space separated keywords
+const TOKEN_LIST_ATTRIBUTES: [&str; 2] = ["rel", "sandbox"];
+
+const BOOLEAN_AUTO_KEYWORDS: [&str; 3] = ["true", "false", "auto"];
+const ON_OFF_KEYWORDS: [&str; 2] = ["on", "off"];
+const YES_NO_KEYWORDS: [&str; 2] = ["yes", "no"];
+
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ValueError {
+    ///
+    UnknownTag {
+        ///
+        tag: String,
+    },
+
+    ///
+    UnknownAttribute {
+        ///
+        tag: String,
+
+        ///
+        attribute: String,
+    },
+
+    ///
+    InvalidKeyword {
+        ///
+        attribute: String,
+
+        ///
+        value: String,
+
+        ///
+        expected: Vec<String>,
+    },
+
+    ///
+    InvalidSyntax {
+        ///
+        attribute: String,
+
+        ///
+        value: String,
+
+        ///
+        expected: &'static str,
+    },
+
+    ///
+    UnknownLanguage {
+        ///
+        attribute: String,
+
+        ///
+        value: String,
+    },
+
+    ///
+    UnknownCountry {
+        ///
+        attribute: String,
+
+        ///
+        value: String,
+    },
+}
+
+impl Display for ValueError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTag { tag } =>
+                write!(fmt, "unknown tag <{tag}>"),
+            Self::UnknownAttribute { tag, attribute } =>
+                write!(fmt, "attribute {attribute:?} is not valid on <{tag}>"),
+            Self::InvalidKeyword { attribute, value, expected } =>
+                write!(fmt, "{value:?} is not a valid {attribute:?} value, expected one of {}", expected.join(", ")),
+            Self::InvalidSyntax { attribute, value, expected } =>
+                write!(fmt, "{value:?} is not a valid {attribute:?} value, expected {expected}"),
+            Self::UnknownLanguage { attribute, value } =>
+                write!(fmt, "{value:?} is not a valid {attribute:?} value, it does not start with a known language code"),
+            Self::UnknownCountry { attribute, value } =>
+                write!(fmt, "{value:?} is not a valid {attribute:?} value, its region is not a known country code"),
+        }
+    }
+}
+
+impl Error for ValueError {}
+
+impl AttributeValue {
+    /// the keywords a value is one of, none when other values are valid too, i.e. token lists,
+    /// descriptive placeholders or a single value describing the expected value
+    #[must_use]
+    pub fn keywords(&self, attribute: &str) -> Option<Vec<&str>> {
+        match self {
+            Self::BooleanAuto { .. } =>
+                Some(BOOLEAN_AUTO_KEYWORDS.to_vec()),
+            Self::OnOff { .. } =>
+                Some(ON_OFF_KEYWORDS.to_vec()),
+            Self::YesNo { .. } =>
+                Some(YES_NO_KEYWORDS.to_vec()),
+            Self::Values(values) if values.len() > 1 && !TOKEN_LIST_ATTRIBUTES.contains(&attribute) &&
+                values.keys().all(|keyword| !is_placeholder(values, keyword)) =>
+                Some(values.keys().map(String::as_str).collect()),
+            _ =>
+                None
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// # use web_reference::prelude::*;
+    /// let types = ["button", "checkbox", "date", "number", "text"].into_iter()
+    ///     .map(|name| (name.to_string(), Value { name: name.to_string(), ..Value::default() }))
+    ///     .collect::<AttributeValues>();
+    ///
+    /// let input_type = AttributeValue::Values(types);
+    ///
+    /// assert!(input_type.validate("type", "number").is_ok());
+    /// assert!(input_type.validate("type", "5").is_err());
+    /// assert!(input_type.validate("type", "2020-01-01").is_err());
+    /// ```
+    ///
+    /// # Errors
+    pub fn validate(&self, attribute: &str, value: &str) -> Result<(), ValueError> {
+        let (valid, expected) = match self {
+            Self::None | Self::Filename { .. } | Self::HTMLCode { .. } | Self::MediaQuery { .. } |
+            Self::RegExp { .. } | Self::Style { .. } | Self::Text { .. } | Self::URLList { .. } =>
+                return Ok(()),
+            Self::Values(values) =>
+                return validate_keywords(values, attribute, value),
+            Self::Boolean { .. } =>
+                (value.is_empty() || value.eq_ignore_ascii_case(attribute), "an empty value or the attribute name"),
+            Self::BooleanAuto { .. } =>
+                (is_keyword(value, &BOOLEAN_AUTO_KEYWORDS), "true, false or auto"),
+            Self::OnOff { .. } =>
+                (is_keyword(value, &ON_OFF_KEYWORDS), "on or off"),
+            Self::YesNo { .. } =>
+                (is_keyword(value, &YES_NO_KEYWORDS), "yes or no"),
+            Self::CharacterSet { .. } =>
+                (regex_is_match!(r#"^[A-Za-z0-9._:+-]+$"#, value), "a character set name"),
+            Self::DateTime { .. } =>
+                (is_date_time(value), "a date, time or duration"),
+            Self::Id { .. } | Self::MapName { .. } =>
+                (is_identifier(value), "a non empty value without whitespace"),
+            Self::LanguageCode { .. } =>
+                (value.is_empty() || is_language_tag(value), "a language code, i.e. en or en-US"),
+            Self::MediaType { .. } =>
+                (is_media_type(value), "a media type, i.e. text/html"),
+            Self::Number { .. } =>
+                (is_number(value), "a number"),
+            Self::Pixels { .. } =>
+                (regex_is_match!(r#"^\d+(px)?$"#, value.trim()), "a non negative number of pixels"),
+            Self::URL { .. } =>
+                (!value.trim().contains(char::is_whitespace), "a url"),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ValueError::InvalidSyntax {
+                attribute: attribute.to_string(),
+                value: value.to_string(),
+                expected,
+            })
+        }
+    }
+}
+
+fn is_date_time(value: &str) -> bool {
+    let value = value.trim();
+
+    regex_is_match!(r#"^\d{4,}(-\d{2}(-\d{2})?)?$"#, value) ||
+        regex_is_match!(r#"^\d{2}-\d{2}$"#, value) ||
+        regex_is_match!(r#"^\d{4,}-W\d{2}$"#, value) ||
+        regex_is_match!(r#"^\d{2}:\d{2}(:\d{2}(\.\d{1,3})?)?$"#, value) ||
+        regex_is_match!(r#"^\d{4,}-\d{2}-\d{2}[T ]\d{2}:\d{2}(:\d{2}(\.\d{1,3})?)?(Z|[+-]\d{2}:?\d{2})?$"#, value) ||
+        regex_is_match!(r#"^(Z|[+-]\d{2}:?\d{2})$"#, value) ||
+        regex_is_match!(r#"^P(\d+D)?(T(\d+H)?(\d+M)?(\d+(\.\d{1,3})?S)?)?$"#, value) && value.len() > 1 && !value.ends_with('T')
+}
+
+fn is_identifier(value: &str) -> bool {
+    !value.is_empty() && !value.contains(char::is_whitespace)
+}
+
+fn is_keyword(value: &str, keywords: &[&str]) -> bool {
+    keywords.iter().any(|keyword| value.eq_ignore_ascii_case(keyword))
+}
+
+fn is_language_tag(value: &str) -> bool {
+    regex_is_match!(r#"^[A-Za-z]{2,8}(-[A-Za-z0-9]{1,8})*$"#, value)
+}
+
+fn is_media_type(value: &str) -> bool {
+    regex_is_match!(r#"^[\w.+-]+/[\w.+*-]+(\s*;.*)?$"#, value.trim())
+}
+
+fn is_number(value: &str) -> bool {
+    regex_is_match!(r#"^-?(\d+(\.\d+)?|\.\d+)([eE][+-]?\d+)?$"#, value)
+}
+
+// "number" and "date" are keywords of `<input type>` too, they only describe a value when the other
+// values do not enumerate keywords, i.e. `<input min>` is a "number" or a "date", `<input step>` a "number" or "any"
+fn is_placeholder(values: &AttributeValues, keyword: &str) -> bool {
+    const ANY: &str = "any";
+    const TYPE_PLACEHOLDERS: [&str; 2] = ["number", "date"];
+
+    is_placeholder_match(keyword, "").is_some() && (
+        !TYPE_PLACEHOLDERS.contains(&keyword) ||
+            values.keys().all(|other| other == ANY || is_placeholder_match(other, "").is_some())
+    )
+}
+
+// descriptive placeholders the reference uses in place of enumerated keywords
+fn is_placeholder_match(placeholder: &str, value: &str) -> Option<bool> {
+    Some(match placeholder {
+        "framename" => is_identifier(value) && !value.starts_with('_'),
+        "(no value)" => value.is_empty(),
+        "number" => is_number(value),
+        "date" => is_date_time(value),
+        "HeightxWidth" => regex_is_match!(r#"^\d+[xX]\d+$"#, value),
+        "media_type" | "file_extension" => !value.is_empty(),
+        coords if coords.starts_with('x') && coords.contains(',') =>
+            regex_is_match!(r#"^-?\d+(\.\d+)?(\s*,\s*-?\d+(\.\d+)?)*$"#, value),
+        _ => return None
+    })
+}
+
+fn validate_keywords(values: &AttributeValues, attribute: &str, value: &str) -> Result<(), ValueError> {
+    // a single value describes the expected value rather than enumerating keywords
+    if values.len() < 2 {
+        return Ok(());
+    }
+
+    let is_valid = |value: &str| {
+        values.keys().any(|keyword| keyword == value) ||
+            values.keys().filter(|keyword| keyword.eq_ignore_ascii_case(value)).count() == 1 ||
+            values.keys().any(|keyword| is_placeholder(values, keyword) && is_placeholder_match(keyword, value).unwrap_or(false))
+    };
+
+    let valid = if TOKEN_LIST_ATTRIBUTES.contains(&attribute) {
+        value.split_ascii_whitespace().all(is_valid) || value.trim().is_empty() && is_valid("")
+    } else {
+        is_valid(value.trim())
+    };
+
+    if valid {
+        return Ok(());
+    }
+
+    let mut expected = values.keys()
+        .filter(|keyword| !is_placeholder(values, keyword))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // values of placeholders only, i.e. a "number" or a "date"
+    if expected.is_empty() {
+        expected = values.keys().cloned().collect();
+    }
+
+    expected.sort();
+
+    Err(ValueError::InvalidKeyword {
+        attribute: attribute.to_string(),
+        value: value.to_string(),
+        expected,
+    })
+}
@@ -1,3 +1,8 @@
+// rkyv leaves the fields of the resolvers it derives for enum variants with fields undocumented
+#![cfg_attr(feature = "binary", allow(missing_docs))]
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use crate::prelude::*;
@@ -5,6 +10,7 @@ use crate::prelude::*;
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Browser {
@@ -24,9 +30,214 @@ pub enum Browser {
     Opera,
 }
 
+impl Display for Browser {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Chrome => "chrome",
+            Self::Edge => "edge",
+            Self::Firefox => "firefox",
+            Self::Safari => "safari",
+            Self::Opera => "opera",
+        })
+    }
+}
+
+impl FromStr for Browser {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Ok(match src.trim().to_lowercase().as_str() {
+            "chrome" => Self::Chrome,
+            "edge" => Self::Edge,
+            "firefox" => Self::Firefox,
+            "safari" => Self::Safari,
+            "opera" => Self::Opera,
+            unexpected => return Err(format!("{unexpected:?} is not a valid browser"))
+        })
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct BrowserTargets {
+    ///
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub minimum: TargetVersions,
+}
+
+impl BrowserTargets {
+    ///
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    #[must_use]
+    pub fn target(mut self, browser: Browser, version: BrowserVersion) -> Self {
+        self.minimum.insert(browser, version);
+
+        self
+    }
+
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.minimum.is_empty()
+    }
+
+    /// a browser without any support details is assumed to be supported
+    #[must_use]
+    pub fn is_supported(&self, supported: &BrowsersSupported) -> bool {
+        self.unsupported_browsers(supported).is_empty()
+    }
+
+    ///
+    #[must_use]
+    pub fn unsupported_browsers(&self, supported: &BrowsersSupported) -> Vec<Browser> {
+        self.minimum.iter()
+            .filter_map(|(browser, minimum)| match supported.get(browser) {
+                None | Some(Support::Unknown) => None,
+                Some(support) if support.is_supported_in(*minimum) => None,
+                Some(_) => Some(*browser)
+            })
+            .collect()
+    }
+}
+
+impl FromStr for BrowserTargets {
+    type Err = String;
+
+    // i.e. "chrome >= 60, safari >= 12.1"
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        src.split(',')
+            .filter(|target| !target.trim().is_empty())
+            .try_fold(Self::new(), |targets, target| {
+                let (_match, browser, version) = regex_captures!(r#"^\s*(\w+)\s*(?:>=)?\s*([\d.]+)\s*$"#, target)
+                    .ok_or_else(|| format!("{target:?} is not a valid browser target"))?;
+
+                Ok(targets.target(Browser::from_str(browser)?, BrowserVersion::from_str(version)?))
+            })
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(feature = "deserialize", serde(try_from = "String"))]
+#[cfg_attr(feature = "serialize", serde(into = "String"))]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BrowserVersion {
+    ///
+    pub major: u32,
+
+    ///
+    pub minor: u32,
+}
+
+impl BrowserVersion {
+    ///
+    #[must_use]
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Display for BrowserVersion {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl FromStr for BrowserVersion {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let (_match, major, minor) = regex_captures!(r#"^(\d+)(?:\.(\d+))?$"#, src.trim())
+            .ok_or_else(|| format!("{src:?} is not a valid browser version"))?;
+
+        Ok(Self {
+            major: major.parse().map_err(|err| format!("{src:?} is not a valid major version: {err}"))?,
+            minor: if minor.is_empty() {
+                0
+            } else {
+                minor.parse().map_err(|err| format!("{src:?} is not a valid minor version: {err}"))?
+            },
+        })
+    }
+}
+
+impl TryFrom<String> for BrowserVersion {
+    type Error = String;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<BrowserVersion> for String {
+    #[inline]
+    fn from(value: BrowserVersion) -> Self {
+        value.to_string()
+    }
+}
+
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum VendorPrefix {
+    ///
+    Webkit,
+
+    ///
+    Moz,
+
+    ///
+    Ms,
+
+    ///
+    O,
+}
+
+impl Display for VendorPrefix {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Webkit => "-webkit-",
+            Self::Moz => "-moz-",
+            Self::Ms => "-ms-",
+            Self::O => "-o-",
+        })
+    }
+}
+
+impl FromStr for VendorPrefix {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Ok(match src.trim().trim_matches(|c| c == '-' || c == '(' || c == ')') {
+            "webkit" => Self::Webkit,
+            "moz" => Self::Moz,
+            "ms" => Self::Ms,
+            "o" => Self::O,
+            unexpected => return Err(format!("{unexpected:?} is not a valid vendor prefix"))
+        })
+    }
+}
+
+/// the support of a browser as listed in browser support tables, i.e. `"16.05.0 moz"` or `"15.0 webkit12.1"`
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Support {
@@ -36,10 +247,23 @@ pub enum Support {
     ///
     No,
 
+    ///
+    Unknown,
+
     ///
     Version {
+        /// the version listed with `prefix`, if it has one
+        version: BrowserVersion,
+
         ///
-        version: String,
+        #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "deserialize", serde(default))]
+        prefix: Option<VendorPrefix>,
+
+        /// the other listed version, without the prefix
+        #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+        #[cfg_attr(feature = "deserialize", serde(default))]
+        unprefixed: Option<BrowserVersion>,
 
         ///
         caveat: usize,
@@ -50,24 +274,24 @@ impl FromStr for Support {
     type Err = String;
 
     fn from_str(src: &str) -> std::result::Result<Self, Self::Err> {
+        let src = src.trim();
+
         Ok(
             match src {
                 "Yes" => Self::Yes,
                 "No" => Self::No,
-                version if version.ends_with('*') => {
-                    let number = version.trim_end_matches('*');
-                    if number.parse::<f32>().is_err() {
-                        return Err(format!("{version} is not valid value for version"));
-                    }
-                    Self::Version {
-                        version: number.to_string(),
-                        caveat: version.len() - number.len(),
-                    }
-                }
+                "?" => Self::Unknown,
+                not_supported if not_supported.eq_ignore_ascii_case("not supported") => Self::No,
                 version => {
+                    let number = version.trim_end_matches('*');
+                    let (version, prefix, unprefixed) = parse_version_support(number)
+                        .map_err(|err| format!("{version} is not valid value for version: {err}"))?;
+
                     Self::Version {
-                        version: version.to_string(),
-                        caveat: 0,
+                        version,
+                        prefix,
+                        unprefixed,
+                        caveat: src.len() - number.len(),
                     }
                 }
             }
@@ -75,22 +299,106 @@ impl FromStr for Support {
     }
 }
 
+impl Display for Support {
+    // i.e. "yes" or "5.0 -moz- 16.0"
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yes => fmt.write_str("yes"),
+            Self::No => fmt.write_str("no"),
+            Self::Unknown => fmt.write_str("unknown"),
+            Self::Version { version, prefix, unprefixed, .. } => {
+                write!(fmt, "{version}")?;
+
+                if let Some(prefix) = prefix {
+                    write!(fmt, " {prefix}")?;
+                }
+
+                if let Some(unprefixed) = unprefixed {
+                    write!(fmt, " {unprefixed}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Support {
+    /// the first supported version, with or without a prefix
+    #[must_use]
+    pub fn since(&self) -> Option<BrowserVersion> {
+        match self {
+            Self::Version { version, unprefixed, .. } =>
+                Some(unprefixed.map_or(*version, |unprefixed| unprefixed.min(*version))),
+            Self::Yes | Self::No | Self::Unknown =>
+                None
+        }
+    }
+
+    ///
+    #[must_use]
+    pub const fn unprefixed_since(&self) -> Option<BrowserVersion> {
+        match self {
+            Self::Version { version, prefix: None, .. } =>
+                Some(*version),
+            Self::Version { unprefixed, .. } =>
+                *unprefixed,
+            Self::Yes | Self::No | Self::Unknown =>
+                None
+        }
+    }
+
+    ///
+    #[must_use]
+    pub fn is_supported_in(&self, version: BrowserVersion) -> bool {
+        match self {
+            Self::Yes => true,
+            Self::No | Self::Unknown => false,
+            Self::Version { .. } => self.since().is_some_and(|since| since <= version)
+        }
+    }
+}
+
+// parses the variations of version support found in browser support tables, i.e.
+// "4.0 webkit", "11.0 -ms-", "45.0 (webkit)", "16.05.0 moz", "26.04.0 (webkitTransitionEnd)"
+// or "15.0 webkit12.1", a vendor prefix applies to the version immediately preceding it
+fn parse_version_support(src: &str) -> Result<(BrowserVersion, Option<VendorPrefix>, Option<BrowserVersion>), String> {
+    if let Ok(version) = BrowserVersion::from_str(src) {
+        return Ok((version, None, None));
+    }
+
+    let (_match, first, second, prefix, third) = regex_captures!(
+        r#"^(\d+(?:\.\d)?)(\d+\.\d+)?\s*[(-]?(webkit|moz|ms|o)(?:TransitionEnd)?[)-]?\s*(\d+\.\d+)?$"#,
+        src
+    ).ok_or_else(|| format!("{src:?} is not a recognized version"))?;
+
+    let prefix = Some(VendorPrefix::from_str(prefix)?);
+    let first = BrowserVersion::from_str(first)?;
+
+    match (second, third) {
+        ("", "") => Ok((first, prefix, None)),
+        (second, "") => Ok((BrowserVersion::from_str(second)?, prefix, Some(first))),
+        ("", third) => Ok((first, prefix, Some(BrowserVersion::from_str(third)?))),
+        _ => Err(format!("{src:?} has too many versions"))
+    }
+}
+
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct SupportedBrowsers {
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub supported: BrowsersSupported,
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub caveats: Caveats,
@@ -107,6 +415,7 @@ impl SupportedBrowsers {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct TagsSupportedBrowsers {
@@ -118,7 +427,7 @@ pub struct TagsSupportedBrowsers {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub attributes: AttributesSupport,
@@ -3,6 +3,7 @@ use crate::prelude::*;
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Field {
@@ -26,6 +27,7 @@ pub struct Field {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Method {
@@ -39,7 +41,7 @@ pub struct Method {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub parameter: Parameters,
@@ -48,6 +50,7 @@ pub struct Method {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Object {
@@ -61,14 +64,14 @@ pub struct Object {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub fields: Fields,
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexMap::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::map::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashMap::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub methods: Methods,
@@ -77,6 +80,7 @@ pub struct Object {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Parameter {
@@ -89,7 +93,7 @@ pub struct Parameter {
     pub description: Description,
 
     ///
-    r#type: Type,
+    pub r#type: Type,
 
     ///
     #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "String::is_empty"))]
@@ -100,6 +104,7 @@ pub struct Parameter {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Type {
@@ -117,4 +122,7 @@ pub enum Type {
 
     ///
     Object,
+
+    /// a type the source does not state
+    Unknown,
 }
@@ -0,0 +1,165 @@
+use crate::prelude::*;
+
+/// what a tag is and what it may contain, following the content models of the html standard
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContentModel {
+    /// the content categories of the tag, i.e. `flow` and `phrasing` of `<span>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub categories: HasContentCategories,
+
+    ///
+    pub content: PermittedContent,
+
+    /// children of the content categories, i.e. `phrasing` of `<p>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub content_categories: HasContentCategories,
+
+    /// children named by the content model, i.e. `<li>` of `<ul>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub content_tags: HasTags,
+
+    /// children of these categories are not allowed, i.e. `interactive` of `<button>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_categories: HasContentCategories,
+
+    /// children that are not allowed, i.e. `<form>` of `<form>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_tags: HasTags,
+
+    /// the only tags the tag may be a child of, i.e. `<ul>`, `<ol>` and `<menu>` of `<li>`, any tag whose
+    /// content allows it when empty
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub parents: HasTags,
+}
+
+impl ContentModel {
+    ///
+    #[must_use]
+    pub fn is_in_category(&self, category: ContentCategory) -> bool {
+        self.categories.contains(&category)
+    }
+
+    /// whether the content allows a child, disregarding the child's permitted parents
+    #[must_use]
+    pub fn permits(&self, child: &str, model: &Self) -> bool {
+        if self.excluded_tags.contains(child) || model.categories.iter().any(|category| self.excluded_categories.contains(category)) {
+            return false;
+        }
+
+        match self.content {
+            PermittedContent::Any =>
+                true,
+            PermittedContent::Nothing | PermittedContent::Text =>
+                false,
+            // the content of the parent's parent is unknown, it is checked as flow content
+            PermittedContent::Transparent =>
+                self.content_tags.contains(child) || model.is_in_category(ContentCategory::Flow),
+            PermittedContent::Elements =>
+                self.content_tags.contains(child) ||
+                    model.categories.iter().any(|category| self.content_categories.contains(category)),
+        }
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PermittedContent {
+    /// void tags, i.e. `<br>`
+    Nothing,
+
+    /// text but no tags, i.e. `<title>`
+    Text,
+
+    /// the content its parent permits, i.e. `<a>`
+    Transparent,
+
+    /// tags of the content categories or content tags, with text if phrasing content is permitted
+    Elements,
+
+    /// any tag regardless of its permitted parents, i.e. `<template>`
+    Any,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ContentCategory {
+    ///
+    Metadata,
+
+    ///
+    Flow,
+
+    ///
+    Sectioning,
+
+    ///
+    Heading,
+
+    ///
+    Phrasing,
+
+    ///
+    Embedded,
+
+    ///
+    Interactive,
+
+    ///
+    Palpable,
+
+    /// `<script>` and `<template>`, allowed where their content model is not
+    ScriptSupporting,
+}
+
+impl<'a> TryFrom<&'a str> for ContentCategory {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "metadata" =>
+                Self::Metadata,
+            "flow" =>
+                Self::Flow,
+            "sectioning" =>
+                Self::Sectioning,
+            "heading" =>
+                Self::Heading,
+            "phrasing" =>
+                Self::Phrasing,
+            "embedded" =>
+                Self::Embedded,
+            "interactive" =>
+                Self::Interactive,
+            "palpable" =>
+                Self::Palpable,
+            "script-supporting" | "scriptsupporting" =>
+                Self::ScriptSupporting,
+            unexpected =>
+                return Err(format!("{unexpected:?} is not a valid content category"))
+        })
+    }
+}
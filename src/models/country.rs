@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Country {
+    ///
+    pub name: String,
+
+    /// the official name of the country, when it differs from its name
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Description::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Description,
+
+    /// ISO 3166-1 alpha-2, alpha-3 and numeric codes of the country, i.e. `GB`, `GBR` and `826`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub codes: CountryCodes,
+}
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CssProperty {
+    ///
+    pub name: String,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Description::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Description,
+
+    /// value definition syntax, i.e. `start | end | left | right | center | justify | match-parent`
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "String::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub syntax: String,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "String::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub initial: String,
+
+    ///
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub inherited: bool,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "SupportedBrowsers::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub supported: SupportedBrowsers,
+
+    /// obsolete presentational attributes the property replaces, i.e. `bgcolor` for `background-color`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub presentational_attributes: HasAttributes,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CssCategoryDetails {
+    ///
+    pub category: CssCategory,
+
+    ///
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub properties: HasCssProperties,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CssCategory {
+    ///
+    Animation,
+
+    ///
+    Background,
+
+    ///
+    Border,
+
+    ///
+    BoxModel,
+
+    ///
+    Color,
+
+    ///
+    Flexbox,
+
+    ///
+    Font,
+
+    ///
+    Grid,
+
+    ///
+    List,
+
+    ///
+    Positioning,
+
+    ///
+    Table,
+
+    ///
+    Text,
+
+    ///
+    Transform,
+
+    ///
+    Misc,
+}
+
+impl<'a> TryFrom<&'a str> for CssCategory {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "animation" | "animations" | "transitions" =>
+                Self::Animation,
+            "background" | "backgrounds" =>
+                Self::Background,
+            "border" | "borders" =>
+                Self::Border,
+            "box" | "box-model" | "boxmodel" =>
+                Self::BoxModel,
+            "color" =>
+                Self::Color,
+            "flexbox" | "flex" =>
+                Self::Flexbox,
+            "font" | "fonts" =>
+                Self::Font,
+            "grid" =>
+                Self::Grid,
+            "list" | "lists" =>
+                Self::List,
+            "position" | "positioning" =>
+                Self::Positioning,
+            "table" | "tables" =>
+                Self::Table,
+            "text" | "text-decor" =>
+                Self::Text,
+            "transform" | "transforms" =>
+                Self::Transform,
+            "misc" =>
+                Self::Misc,
+            unexpected =>
+                return Err(format!("{unexpected:?} is not a valid css category"))
+        })
+    }
+}
+
+/// a human readable suggestion of css properties, i.e. `use CSS font-family or font-size instead`
+#[must_use]
+pub fn css_suggestion(properties: &AlternativeCssProperties) -> String {
+    if properties.is_empty() {
+        return "use CSS instead".to_string();
+    }
+
+    let properties = properties.iter().map(String::as_str).collect::<Vec<_>>().join(" or ");
+
+    format!("use CSS {properties} instead")
+}
@@ -0,0 +1,289 @@
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+
+/// an interned tag name, the index of the tag in the arena of an [`InternedReference`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TagId(u32);
+
+/// an interned attribute name, attributes of the same name are defined once per tag
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct AttrId(u32);
+
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EventId(u32);
+
+macro_rules! symbol_id {
+    ($id:ident) => {
+        impl $id {
+            /// the index of the entity in its arena
+            #[must_use]
+            pub const fn index(self) -> usize {
+                self.0 as usize
+            }
+
+            fn new(index: usize) -> Self {
+                Self(u32::try_from(index).expect("expect fewer than 2^32 entities"))
+            }
+        }
+    };
+}
+
+symbol_id!(TagId);
+symbol_id!(AttrId);
+symbol_id!(EventId);
+
+/// a reference whose tags, attributes and events are interned into arenas and refer to each other by id
+///
+/// the cross references and their reverse are resolved once, so following them neither hashes nor allocates,
+/// an optional accelerator over the lookups of [`WebReference`] for callers that repeat them
+#[derive(Debug)]
+pub struct InternedReference<'a> {
+    tag_ids: Map<&'a str, TagId>,
+    attribute_ids: Map<&'a str, AttrId>,
+    // by name with and without the `on` prefix, so either is a single lookup
+    event_ids: Map<&'a str, EventId>,
+    tags: Vec<InternedTag<'a>>,
+    attributes: Vec<InternedAttribute<'a>>,
+    events: Vec<InternedEvent<'a>>,
+    tags_categorized: Vec<Vec<TagId>>,
+    events_categorized: Vec<Vec<EventId>>,
+}
+
+#[derive(Debug)]
+struct InternedTag<'a> {
+    tag: &'a Tag,
+    attribute_ids: Vec<AttrId>,
+    attributes: Vec<&'a Attribute>,
+    events: Vec<EventId>,
+}
+
+#[derive(Debug)]
+struct InternedAttribute<'a> {
+    name: &'a str,
+    global: Option<&'a Attribute>,
+    tags: Vec<TagId>,
+    definitions: Vec<&'a Attribute>,
+}
+
+#[derive(Debug)]
+struct InternedEvent<'a> {
+    event: &'a Event,
+    tags: Vec<TagId>,
+}
+
+impl<'a> InternedReference<'a> {
+    ///
+    #[must_use]
+    pub fn new(reference: &'a WebReference) -> Self {
+        const GLOBAL: &str = "global";
+
+        let tag_ids = reference.tags.keys()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), TagId::new(index)))
+            .collect::<Map<_, _>>();
+
+        let attribute_ids = reference.attributes.keys()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), AttrId::new(index)))
+            .collect::<Map<_, _>>();
+
+        let mut event_ids = reference.events.keys()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), EventId::new(index)))
+            .collect::<Map<_, _>>();
+
+        // prefixed names win over an unprefixed name that happens to be the same
+        for (index, name) in reference.events.keys().enumerate() {
+            if let Some(name) = name.strip_prefix(EVENT_PREFIX) {
+                event_ids.entry(name).or_insert_with(|| EventId::new(index));
+            }
+        }
+
+        let mut tags = reference.tags.values()
+            .map(|tag| {
+                let names = tag.attributes.iter().chain(tag.optional_attributes.iter());
+
+                InternedTag {
+                    tag,
+                    attribute_ids: names.clone().filter_map(|name| attribute_ids.get(name.as_str()).copied()).collect(),
+                    attributes: names.filter_map(|name| reference.attributes.get(name)?.get(&tag.name)).collect(),
+                    events: Vec::new(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let attributes = reference.attributes.iter()
+            .map(|(name, tags)| {
+                // definitions sorted by tag, so the definition of a tag is a binary search
+                let mut definitions = tags.iter()
+                    .filter_map(|(tag, attribute)| Some((*tag_ids.get(tag.as_str())?, attribute)))
+                    .collect::<Vec<_>>();
+
+                definitions.sort_by_key(|(tag, _)| *tag);
+
+                InternedAttribute {
+                    name,
+                    global: tags.get(GLOBAL),
+                    tags: definitions.iter().map(|(tag, _)| *tag).collect(),
+                    definitions: definitions.into_iter().map(|(_, attribute)| attribute).collect(),
+                }
+            })
+            .collect();
+
+        let events = reference.events.values()
+            .enumerate()
+            .map(|(index, event)| {
+                let belongs_to = match &event.belongs_to {
+                    EventBelongsTo::Tags(names) => names.iter()
+                        .filter_map(|name| tag_ids.get(name.as_str()).copied())
+                        .collect(),
+                    EventBelongsTo::NotDefined | EventBelongsTo::AllVisible => Vec::new(),
+                };
+
+                for tag in &belongs_to {
+                    tags[tag.index()].events.push(EventId::new(index));
+                }
+
+                InternedEvent { event, tags: belongs_to }
+            })
+            .collect();
+
+        let mut tags_categorized = Vec::<Vec<TagId>>::new();
+
+        for details in reference.tags_categorized.values() {
+            let category = details.category as usize;
+
+            if tags_categorized.len() <= category {
+                tags_categorized.resize_with(category + 1, Vec::new);
+            }
+
+            tags_categorized[category] = details.tags.iter()
+                .filter_map(|name| tag_ids.get(name.as_str()).copied())
+                .collect();
+        }
+
+        let mut events_categorized = Vec::<Vec<EventId>>::new();
+
+        for details in reference.events_categorized.values() {
+            let category = details.category as usize;
+
+            if events_categorized.len() <= category {
+                events_categorized.resize_with(category + 1, Vec::new);
+            }
+
+            events_categorized[category] = details.events.iter()
+                .filter_map(|name| event_ids.get(name.as_str()).copied())
+                .collect();
+        }
+
+        Self { tag_ids, attribute_ids, event_ids, tags, attributes, events, tags_categorized, events_categorized }
+    }
+
+    ///
+    #[must_use]
+    pub fn tag_id(&self, tag: &str) -> Option<TagId> {
+        self.tag_ids.get(tag).copied()
+    }
+
+    ///
+    #[must_use]
+    pub fn attribute_id(&self, attribute: &str) -> Option<AttrId> {
+        self.attribute_ids.get(attribute).copied()
+    }
+
+    /// the id of an event by its name, with or without its `on` prefix
+    #[must_use]
+    pub fn event_id(&self, event: &str) -> Option<EventId> {
+        self.event_ids.get(event).copied()
+    }
+
+    ///
+    #[must_use]
+    pub fn tag(&self, tag: TagId) -> &'a Tag {
+        self.tags[tag.index()].tag
+    }
+
+    ///
+    #[must_use]
+    pub fn attribute_name(&self, attribute: AttrId) -> &'a str {
+        self.attributes[attribute.index()].name
+    }
+
+    /// an attribute as it is defined for a tag, see [`InternedReference::get_global_attribute`] for global attributes
+    #[must_use]
+    pub fn get_attribute(&self, attribute: AttrId, tag: TagId) -> Option<&'a Attribute> {
+        let attribute = &self.attributes[attribute.index()];
+
+        attribute.tags.binary_search(&tag).ok().map(|index| attribute.definitions[index])
+    }
+
+    ///
+    #[must_use]
+    pub fn get_global_attribute(&self, attribute: AttrId) -> Option<&'a Attribute> {
+        self.attributes[attribute.index()].global
+    }
+
+    ///
+    #[must_use]
+    pub fn event(&self, event: EventId) -> &'a Event {
+        self.events[event.index()].event
+    }
+
+    /// the tags that define an attribute, in order of their ids
+    #[must_use]
+    pub fn get_attribute_tags(&self, attribute: AttrId) -> &[TagId] {
+        &self.attributes[attribute.index()].tags
+    }
+
+    /// the tags an event belongs to, empty for events of all visible tags
+    #[must_use]
+    pub fn get_event_tags(&self, event: EventId) -> &[TagId] {
+        &self.events[event.index()].tags
+    }
+
+    ///
+    #[must_use]
+    pub fn get_events_of_category(&self, category: EventCategory) -> &[EventId] {
+        self.events_categorized.get(category as usize).map_or(&[], Vec::as_slice)
+    }
+
+    /// the required and optional attributes defined for a tag, without global attributes
+    #[must_use]
+    pub fn get_tag_attributes(&self, tag: TagId) -> &[&'a Attribute] {
+        &self.tags[tag.index()].attributes
+    }
+
+    /// the names of the required and optional attributes of a tag, whether or not they are defined for it
+    #[must_use]
+    pub fn get_tag_attribute_ids(&self, tag: TagId) -> &[AttrId] {
+        &self.tags[tag.index()].attribute_ids
+    }
+
+    /// the events that belong to a tag specifically, without the events of all visible tags
+    #[must_use]
+    pub fn get_tag_events(&self, tag: TagId) -> &[EventId] {
+        &self.tags[tag.index()].events
+    }
+
+    ///
+    #[must_use]
+    pub fn get_tags_of_category(&self, category: TagCategory) -> &[TagId] {
+        self.tags_categorized.get(category as usize).map_or(&[], Vec::as_slice)
+    }
+
+    ///
+    pub fn tag_ids(&self) -> impl Iterator<Item=TagId> {
+        (0..self.tags.len()).map(TagId::new)
+    }
+
+    ///
+    pub fn attribute_ids(&self) -> impl Iterator<Item=AttrId> {
+        (0..self.attributes.len()).map(AttrId::new)
+    }
+
+    ///
+    pub fn event_ids(&self) -> impl Iterator<Item=EventId> {
+        (0..self.events.len()).map(EventId::new)
+    }
+}
@@ -3,6 +3,7 @@ use crate::prelude::*;
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Language {
@@ -14,9 +15,8 @@ pub struct Language {
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub description: Description,
 
-    ///
+    /// ISO 639-1 and ISO 639-2 codes of the language, i.e. `de`, `deu` and `ger`
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub codes: LanguageCodes,
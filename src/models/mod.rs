@@ -1,13 +1,35 @@
 //!
 
+pub mod aria;
 pub mod attr;
+pub mod attr_value;
 pub mod browser;
 pub mod common;
+pub mod content;
+pub mod country;
+pub mod css;
 pub mod lang;
+pub mod search;
 pub mod event;
+pub mod interned;
+pub mod syntax;
 pub mod tag;
 pub mod types;
 pub mod web;
+pub mod web_impl_aria;
+pub mod web_impl_compat;
+pub mod web_impl_content;
+pub mod web_impl_css;
+pub mod web_impl_interned;
+pub mod web_impl_namespace;
+pub mod web_impl_search;
+pub mod web_impl_suggest;
+pub mod web_impl_syntax;
+pub mod web_impl_values;
+#[cfg(feature = "embedded-specs")]
+pub mod web_impl_embedded;
+#[cfg(feature = "migrate")]
+pub mod web_impl_migrate;
 #[cfg(feature = "html5-specs")]
 pub mod web_impl_specs;
 pub mod web_impl;
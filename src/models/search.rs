@@ -0,0 +1,303 @@
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+
+// words too common in the reference's descriptions to tell entities apart
+const STOP_WORDS: [&str; 40] = [
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "defines", "does", "element", "elements",
+    "for", "from", "how", "i", "in", "is", "it", "make", "makes", "of", "on", "or", "should",
+    "specifies", "tag", "tags", "that", "the", "this", "to", "use", "used", "what", "when", "which",
+    "with", "you",
+];
+
+const SNIPPET_AFTER: usize = 12;
+const SNIPPET_BEFORE: usize = 6;
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SearchKind {
+    ///
+    Tag,
+
+    ///
+    Attribute,
+
+    ///
+    Value,
+
+    ///
+    Event,
+}
+
+/// a ranked search result, `tag` and `attribute` scope attributes and values to where they are defined
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    ///
+    pub kind: SearchKind,
+
+    ///
+    pub name: String,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub tag: Option<String>,
+
+    ///
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub attribute: Option<String>,
+
+    ///
+    pub score: f64,
+
+    /// the best matching text, with matching words highlighted as `**bold**`
+    pub snippet: String,
+}
+
+/// an inverted index over names, descriptions, value descriptions and caveats of a reference
+#[derive(Debug)]
+pub struct SearchIndex<'a> {
+    documents: Vec<Document<'a>>,
+    postings: Map<String, Vec<Posting>>,
+}
+
+#[derive(Debug)]
+struct Document<'a> {
+    kind: SearchKind,
+    name: &'a str,
+    tag: Option<&'a str>,
+    attribute: Option<&'a str>,
+    fields: Vec<(Field, &'a str)>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Field {
+    Name,
+    Description,
+    Caveat,
+}
+
+impl Field {
+    const fn weight(self) -> f64 {
+        match self {
+            Self::Name => 4.0,
+            Self::Description => 2.0,
+            Self::Caveat => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Posting {
+    document: usize,
+    field: usize,
+}
+
+impl<'a> SearchIndex<'a> {
+    ///
+    #[must_use]
+    pub fn new(reference: &'a WebReference) -> Self {
+        let mut index = Self { documents: Vec::new(), postings: Map::new() };
+
+        for tag in reference.tags.values() {
+            index.add(SearchKind::Tag, &tag.name, None, None, &tag.description, Some(&tag.supported));
+        }
+
+        for (tag, attribute) in reference.attributes.values().flat_map(|tags| tags.iter()) {
+            index.add(SearchKind::Attribute, &attribute.name, Some(tag), None, &attribute.description, Some(&attribute.supported));
+
+            if let AttributeValue::Values(values) = &attribute.values {
+                for value in values.values() {
+                    index.add(SearchKind::Value, &value.name, Some(tag), Some(&attribute.name), &value.description, None);
+                }
+            }
+        }
+
+        for event in reference.events.values() {
+            index.add(SearchKind::Event, &event.name, None, None, &event.description, Some(&event.supported));
+        }
+
+        index
+    }
+
+    /// hits for all entities matching any word of `query`, best match first
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let mut terms = tokenize(query).collect::<Vec<_>>();
+
+        terms.sort_unstable();
+        terms.dedup();
+
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores = Map::<usize, (f64, usize)>::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let total = self.documents.len() as f64;
+
+        for term in &terms {
+            let mut matched = Map::<usize, f64>::new();
+
+            // an exact term, otherwise words it is the start of, i.e. `collaps` for `collapsible`
+            let postings = self.postings.get(term).map_or_else(
+                || self.postings.iter()
+                    .filter(|(word, _)| term.len() >= 3 && word.starts_with(term.as_str()))
+                    .flat_map(|(_, postings)| postings.iter().map(|posting| (posting, 0.5)))
+                    .collect::<Vec<_>>(),
+                |postings| postings.iter().map(|posting| (posting, 1.0)).collect(),
+            );
+
+            for (posting, factor) in &postings {
+                let (field, _) = self.documents[posting.document].fields[posting.field];
+                let weight = matched.entry(posting.document).or_default();
+
+                *weight = weight.max(field.weight() * factor);
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let idf = (total / (matched.len() as f64 + 1.0)).ln() + 1.0;
+
+            for (document, weight) in matched {
+                let (score, hits) = scores.entry(document).or_default();
+
+                *score += weight * idf;
+                *hits += 1;
+            }
+        }
+
+        let mut hits = scores.into_iter()
+            .map(|(document, (score, hits))| {
+                #[allow(clippy::cast_precision_loss)]
+                let coverage = hits as f64 / terms.len() as f64;
+
+                self.hit(document, score * coverage, &terms)
+            })
+            .collect::<Vec<_>>();
+
+        hits.sort_by(|lhs, rhs| {
+            rhs.score.total_cmp(&lhs.score)
+                .then_with(|| lhs.kind.cmp(&rhs.kind))
+                .then_with(|| lhs.name.cmp(&rhs.name))
+                .then_with(|| lhs.tag.cmp(&rhs.tag))
+        });
+
+        hits
+    }
+
+    fn add(
+        &mut self,
+        kind: SearchKind,
+        name: &'a str,
+        tag: Option<&'a str>,
+        attribute: Option<&'a str>,
+        description: &'a Description,
+        supported: Option<&'a SupportedBrowsers>,
+    ) {
+        let document = self.documents.len();
+
+        let mut fields = vec![(Field::Name, name), (Field::Description, description.as_ref())];
+
+        // event handler names are searchable without their prefix, i.e. `click` for `onclick`
+        if let Some(event) = name.strip_prefix(EVENT_PREFIX).filter(|_| kind == SearchKind::Event) {
+            fields.push((Field::Name, event));
+        }
+
+        fields.extend(supported.into_iter().flat_map(|supported| supported.caveats.iter()).map(|caveat| (Field::Caveat, caveat.as_ref())));
+
+        for (field, (_, text)) in fields.iter().enumerate() {
+            for term in tokenize(text) {
+                let postings = self.postings.entry(term).or_default();
+
+                if !postings.iter().any(|posting| posting.document == document && posting.field == field) {
+                    postings.push(Posting { document, field });
+                }
+            }
+        }
+
+        self.documents.push(Document { kind, name, tag, attribute, fields });
+    }
+
+    fn hit(&self, document: usize, score: f64, terms: &[String]) -> SearchHit {
+        let document = &self.documents[document];
+
+        // the most relevant text that mentions any of the terms, favouring descriptions over names
+        let text = document.fields.iter()
+            .filter(|(field, text)| *field != Field::Name && tokenize(text).any(|word| matches(&word, terms)))
+            .map(|(_, text)| *text)
+            .next()
+            .unwrap_or_else(|| document.fields.get(1).map_or(document.name, |(_, text)| text));
+
+        SearchHit {
+            kind: document.kind,
+            name: document.name.to_string(),
+            tag: document.tag.map(String::from),
+            attribute: document.attribute.map(String::from),
+            score,
+            snippet: snippet(text, terms),
+        }
+    }
+}
+
+fn matches(word: &str, terms: &[String]) -> bool {
+    terms.iter().any(|term| word == term || (term.len() >= 3 && word.starts_with(term.as_str())))
+}
+
+// a window of words around the first match, with matching words highlighted
+fn snippet(text: &str, terms: &[String]) -> String {
+    let words = text.split_whitespace().collect::<Vec<_>>();
+
+    let is_match = |word: &str| tokenize(word).any(|word| matches(&word, terms));
+    let first = words.iter().position(|word| is_match(word)).unwrap_or_default();
+
+    let start = first.saturating_sub(SNIPPET_BEFORE);
+    let end = (first + SNIPPET_AFTER).min(words.len());
+
+    let mut snippet = words[start..end].iter()
+        .map(|word| if is_match(word) { format!("**{word}**") } else { (*word).to_string() })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if start > 0 {
+        snippet.insert_str(0, "… ");
+    }
+
+    if end < words.len() {
+        snippet.push_str(" …");
+    }
+
+    snippet
+}
+
+// lowercase words without stop words, with common english suffixes removed
+fn tokenize(text: &str) -> impl Iterator<Item=String> + '_ {
+    text.split(|chr: char| !chr.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .map(|word| stem(&word))
+}
+
+fn stem(word: &str) -> String {
+    const SUFFIXES: [&str; 5] = ["ing", "ed", "es", "ly", "s"];
+
+    let Some(stem) = SUFFIXES.iter().find_map(|suffix| word.strip_suffix(suffix).filter(|stem| stem.len() >= 4)) else {
+        return word.to_string();
+    };
+
+    // undo doubled consonants, i.e. `dragged` to `drag`
+    let mut chars = stem.chars().rev();
+
+    match (chars.next(), chars.next()) {
+        (Some(last), Some(previous)) if last == previous && !"aeiouls".contains(last) =>
+            stem[..stem.len() - last.len_utf8()].to_string(),
+        _ => stem.to_string(),
+    }
+}
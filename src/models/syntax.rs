@@ -0,0 +1,191 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+/// how the html syntax treats a tag, see the kinds of elements and optional tags of the html standard
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TagSyntax {
+    ///
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub kind: ElementKind,
+
+    /// when the start tag may be omitted, never when none
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub start_tag_omission: Option<OmissionRule>,
+
+    /// when the end tag may be omitted, never when none, void tags have no end tag
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub end_tag_omission: Option<OmissionRule>,
+}
+
+impl TagSyntax {
+    ///
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.kind == ElementKind::Normal && self.start_tag_omission.is_none() && self.end_tag_omission.is_none()
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ElementKind {
+    /// no content and no end tag, i.e. `<br>`
+    Void,
+
+    ///
+    Template,
+
+    /// text that is not parsed for character references, i.e. `<script>`
+    RawText,
+
+    /// text with character references but no tags, i.e. `<textarea>`
+    EscapableRawText,
+
+    /// the root of content in another namespace, i.e. `<svg>`
+    Foreign,
+
+    ///
+    #[default]
+    Normal,
+}
+
+impl<'a> TryFrom<&'a str> for ElementKind {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "void" =>
+                Self::Void,
+            "template" =>
+                Self::Template,
+            "raw-text" | "rawtext" =>
+                Self::RawText,
+            "escapable-raw-text" | "escapablerawtext" =>
+                Self::EscapableRawText,
+            "foreign" =>
+                Self::Foreign,
+            "normal" =>
+                Self::Normal,
+            unexpected =>
+                return Err(format!("{unexpected:?} is not a valid element kind"))
+        })
+    }
+}
+
+impl Display for ElementKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Void => "void",
+            Self::Template => "template",
+            Self::RawText => "raw text",
+            Self::EscapableRawText => "escapable raw text",
+            Self::Foreign => "foreign",
+            Self::Normal => "normal",
+        })
+    }
+}
+
+/// the conditions under which a start or end tag may be omitted, any of them suffices, the next node is the
+/// first one inside the element for a start tag and the one after the element for an end tag
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)] // the html standard's conditions are independent flags
+pub struct OmissionRule {
+    /// the condition as the html standard words it
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Description::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub description: Description,
+
+    /// when the element is empty, i.e. `<head>`
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub when_empty: bool,
+
+    /// when no more content follows in the parent, i.e. `</li>`
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub at_parent_end: bool,
+
+    /// parents in which `at_parent_end` does not apply, i.e. `<a>` for `</p>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_parents: HasTags,
+
+    /// when the next node is one of these tags, i.e. `<li>` for `</li>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub next_tags: HasTags,
+
+    /// when the next node is any element, i.e. for `<head>`
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub next_element: bool,
+
+    /// when the next node is anything but one of these tags, i.e. `<script>` for `<body>`
+    #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+    #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_next_tags: HasTags,
+
+    /// when the next node is anything but a comment, i.e. for `</html>`
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_next_comment: bool,
+
+    /// when the next node is anything but ascii whitespace, i.e. for `</head>`
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub excluded_next_whitespace: bool,
+}
+
+impl OmissionRule {
+    /// whether the tag may be omitted before a node in a parent, an unknown parent does not exclude `at_parent_end`
+    #[must_use]
+    pub fn allows(&self, next: NextNode<'_>, parent: Option<&str>) -> bool {
+        let excludes = self.excluded_next_comment || self.excluded_next_whitespace || !self.excluded_next_tags.is_empty();
+
+        match next {
+            NextNode::None =>
+                self.when_empty || excludes ||
+                    (self.at_parent_end && parent.is_none_or(|parent| !self.excluded_parents.contains(parent))),
+            NextNode::Element(tag) =>
+                self.next_tags.contains(tag) || self.next_element || (excludes && !self.excluded_next_tags.contains(tag)),
+            NextNode::Text =>
+                excludes,
+            NextNode::Whitespace =>
+                excludes && !self.excluded_next_whitespace,
+            NextNode::Comment =>
+                excludes && !self.excluded_next_comment,
+        }
+    }
+}
+
+/// the node after an omissible tag, see [`OmissionRule`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NextNode<'a> {
+    /// nothing, the element is empty or no more content follows in its parent
+    None,
+
+    /// an element of a tag, lowercase
+    Element(&'a str),
+
+    /// text that starts with something besides ascii whitespace
+    Text,
+
+    /// text that starts with ascii whitespace
+    Whitespace,
+
+    ///
+    Comment,
+}
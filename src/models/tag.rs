@@ -1,10 +1,13 @@
 // use std::str::FromStr;
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 use crate::prelude::*;
 
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Tag {
@@ -23,14 +26,12 @@ pub struct Tag {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub attributes: HasAttributes,
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub optional_attributes: HasAttributes,
@@ -43,11 +44,27 @@ pub struct Tag {
 
     ///
     pub alternatives: TagAlternatives,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "TagAria::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub aria: TagAria,
+
+    /// unknown for obsolete tags
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub content_model: Option<ContentModel>,
+
+    ///
+    #[cfg_attr(all(feature = "serialize"), serde(skip_serializing_if = "TagSyntax::is_empty"))]
+    #[cfg_attr(feature = "deserialize", serde(default))]
+    pub syntax: TagSyntax,
 }
 
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TagCategoryDetails {
@@ -56,7 +73,6 @@ pub struct TagCategoryDetails {
 
     ///
     #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-    #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
     #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub tags: HasTags,
@@ -65,28 +81,53 @@ pub struct TagCategoryDetails {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TagAlternatives {
     ///
     None,
 
-    ///
-    Css,
+    /// css properties that replace the tag, none when the reference does not know which
+    Css(
+        #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
+        #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
+        #[cfg_attr(feature = "deserialize", serde(default))]
+        AlternativeCssProperties
+    ),
 
     ///
     Tags(
         #[cfg_attr(all(feature = "serialize", feature = "ordered-map"), serde(skip_serializing_if = "indexmap::IndexSet::is_empty"))]
-        #[cfg_attr(all(feature = "serialize", feature = "ordered-map", feature = "serde_json"), serde(with = "indexmap::serde_seq"))]
         #[cfg_attr(all(feature = "serialize", not(feature = "ordered-map")), serde(skip_serializing_if = "HashSet::is_empty"))]
         #[cfg_attr(feature = "deserialize", serde(default))]
         AlternativeTags
     ),
 }
 
+impl TagAlternatives {
+    /// a human readable suggestion for an obsolete tag, i.e. `use <abbr> instead` or `use CSS text-align instead`
+    #[must_use]
+    pub fn suggestion(&self) -> Option<String> {
+        match self {
+            Self::None => None,
+            Self::Css(properties) => Some(css_suggestion(properties)),
+            Self::Tags(alternatives) => {
+                let alternatives = alternatives.iter()
+                    .map(|alternative| format!("<{alternative}>"))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+
+                Some(format!("use {alternatives} instead"))
+            }
+        }
+    }
+}
+
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq), compare(PartialEq)))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum TagCategory {
@@ -160,4 +201,63 @@ impl<'a> TryFrom<&'a str> for TagCategory {
                 return Err(format!("{unexpected:?} is not a valid tag category"))
         })
     }
-}
\ No newline at end of file
+}
+
+/// the namespace a tag or attribute is defined in, an inline `<svg>` switches a document to svg
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Namespace {
+    ///
+    #[default]
+    Html,
+
+    ///
+    Svg,
+}
+
+impl Namespace {
+    /// the tag that switches an html document into the namespace, i.e. `svg`
+    #[must_use]
+    pub const fn root_tag(self) -> Option<&'static str> {
+        match self {
+            Self::Html => None,
+            Self::Svg => Some("svg"),
+        }
+    }
+
+    ///
+    #[must_use]
+    pub const fn uri(self) -> &'static str {
+        match self {
+            Self::Html => "http://www.w3.org/1999/xhtml",
+            Self::Svg => "http://www.w3.org/2000/svg",
+        }
+    }
+}
+
+impl Display for Namespace {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Html => "html",
+            Self::Svg => "svg",
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Namespace {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "html" | "xhtml" =>
+                Self::Html,
+            "svg" =>
+                Self::Svg,
+            unexpected =>
+                return Err(format!("{unexpected:?} is not a valid namespace"))
+        })
+    }
+}
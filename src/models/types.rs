@@ -2,18 +2,24 @@
 
 //!
 
+// serde attributes of the models refer to `HashMap::is_empty` and `HashSet::is_empty`
 #[cfg(not(feature = "ordered-map"))]
-use std::collections::{HashMap, HashSet};
+pub use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
 #[cfg(feature = "ordered-map")]
 use indexmap::{IndexMap, IndexSet};
 
+use super::aria::*;
 use super::attr::*;
 use super::browser::*;
 use super::common::*;
+use super::content::*;
+use super::country::*;
+use super::css::*;
 use super::event::*;
+use super::lang::*;
 use super::tag::*;
 
 ///
@@ -35,12 +41,24 @@ pub type Set<V> = IndexSet<V>;
 ///
 pub type AlternativeEvents = Set<String>;
 
+///
+pub type AriaAttributes = Map<String, AriaAttribute>;
+
+///
+pub type AriaRoles = Map<String, AriaRole>;
+
+///
+pub type AriaTokens = Set<String>;
+
 ///
 pub type AlternativeTags = Set<String>;
 
 ///
 pub type AlternativeAttributes = Set<String>;
 
+///
+pub type AlternativeCssProperties = Set<String>;
+
 ///
 pub type TagAttributes = Map<String, Attribute>;
 
@@ -68,6 +86,18 @@ pub type CategorizedTags = Map<TagCategoryDetails, Tags>;
 ///
 pub type Caveats = Set<Description>;
 
+///
+pub type Countries = Map<String, Country>;
+
+///
+pub type CountryCodes = Set<String>;
+
+///
+pub type CssCategorized = Map<CssCategory, CssCategoryDetails>;
+
+///
+pub type CssProperties = Map<String, CssProperty>;
+
 ///
 pub type EventObjects = Map<String, Object>;
 
@@ -89,6 +119,15 @@ pub type HasAttributes = Set<String>;
 ///
 pub type HasEventObjects = Set<String>;
 
+///
+pub type HasAriaRoles = Set<String>;
+
+///
+pub type HasContentCategories = Set<ContentCategory>;
+
+///
+pub type HasCssProperties = Set<String>;
+
 ///
 pub type HasEvents = Set<String>;
 
@@ -98,18 +137,27 @@ pub type HasTags = Set<String>;
 ///
 pub type LanguageCodes = Set<String>;
 
+///
+pub type Languages = Map<String, Language>;
+
 ///
 pub type Methods = Map<String, Method>;
 
 ///
 pub type Parameters = Map<String, Parameter>;
 
+///
+pub type SuperclassRoles = Set<String>;
+
 ///
 pub type Supported = Map<String, BrowsersSupported>;
 
 ///
 pub type Tags = Map<String, Tag>;
 
+///
+pub type TargetVersions = Map<Browser, BrowserVersion>;
+
 ///
 pub type TagsCategorized = Map<TagCategory, TagCategoryDetails>;
 
@@ -120,6 +168,7 @@ pub type TagsSupport = Map<String, TagsSupportedBrowsers>;
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Value {
@@ -135,6 +184,7 @@ pub struct Value {
 ///
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "binary", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(derive(Hash, PartialEq, Eq)))]
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Description(String);
 
@@ -196,6 +246,12 @@ impl Description {
         self.0.is_empty()
     }
 
+    /// a description taken as is, i.e. one that was already normalized before it was stored
+    #[must_use]
+    pub const fn verbatim(description: String) -> Self {
+        Self(description)
+    }
+
     ///
     #[must_use]
     pub fn combine(&self, other: &Self) -> Self {
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-const EVENT_PREFIX: &str = "on";
+pub const EVENT_PREFIX: &str = "on";
 
 impl WebReference {
     ///
@@ -60,6 +60,21 @@ impl WebReference {
         )
     }
 
+    /// a country by any of its codes, i.e. `GB`, `gbr` or `826`
+    #[must_use]
+    pub fn get_country(&self, code: &str) -> Option<&Country> {
+        let code = code.to_ascii_uppercase();
+
+        self.countries.get(&code)
+            .or_else(|| self.countries.values().find(|country| country.codes.contains(&code)))
+    }
+
+    /// the country of a language tag's region subtag, i.e. `GB` of `en-GB` or `zh-Hant-TW`
+    #[must_use]
+    pub fn get_language_country(&self, tag: &str) -> Option<&Country> {
+        language_region(tag).and_then(|region| self.get_country(region))
+    }
+
     ///
     #[must_use]
     pub fn get_event(&self, event: &str) -> Option<&Event> {
@@ -72,6 +87,26 @@ impl WebReference {
         self.events_categorized.get(&category)
     }
 
+    /// an object passed to event handlers, i.e. `MouseEvent`
+    #[must_use]
+    pub fn get_event_object(&self, name: &str) -> Option<&Object> {
+        self.event_objects.get(name)
+    }
+
+    /// fields of all the objects passed to an event's handlers, none if none of its objects are defined
+    #[must_use]
+    pub fn get_event_fields(&self, event: &Event) -> Option<Vec<&Field>> {
+        let objects = event.event_objects.iter()
+            .filter_map(|object| self.get_event_object(object))
+            .collect::<Vec<_>>();
+
+        if objects.is_empty() {
+            return None;
+        }
+
+        Some(objects.into_iter().flat_map(|object| object.fields.values()).collect())
+    }
+
     ///
     #[must_use]
     pub fn get_events_of_category(&self, category: EventCategory) -> Option<Vec<&Event>> {
@@ -83,6 +118,34 @@ impl WebReference {
         )
     }
 
+    /// a language by any of its codes, or the primary subtag of a language tag, i.e. `en`, `eng` or `en-US`
+    #[must_use]
+    pub fn get_language(&self, code: &str) -> Option<&Language> {
+        let code = code.split('-').next().unwrap_or_default().to_ascii_lowercase();
+
+        self.languages.get(&code)
+            .or_else(|| self.languages.values().find(|language| language.codes.contains(&code)))
+    }
+
+    /// whether a language tag starts with a known language, private use tags, i.e. `x-klingon`,
+    /// and references without a catalog of languages accept any tag
+    #[must_use]
+    pub fn is_valid_language(&self, tag: &str) -> bool {
+        const PRIVATE_USE: &str = "x";
+
+        self.languages.is_empty() ||
+            tag.split('-').next().is_some_and(|primary| primary.eq_ignore_ascii_case(PRIVATE_USE)) ||
+            self.get_language(tag).is_some()
+    }
+
+    /// whether a language tag's region is a known country, tags without a two letter region,
+    /// i.e. `en` or `es-419`, and references without a catalog of countries accept any tag
+    #[must_use]
+    pub fn is_valid_language_region(&self, tag: &str) -> bool {
+        self.countries.is_empty() ||
+            language_region(tag).is_none_or(|region| self.get_country(region).is_some())
+    }
+
     ///
     #[must_use]
     pub fn get_tag(&self, tag: &str) -> Option<&Tag> {
@@ -95,6 +158,28 @@ impl WebReference {
         self.attributes.get(attribute)?.get(&tag.name)
     }
 
+    ///
+    #[must_use]
+    pub fn find_tag_attribute(&self, attribute: &str, tag: &Tag) -> Option<&Attribute> {
+        const GLOBAL: &str = "global";
+        const DATA_ATTRIBUTE: &str = "data-*";
+        const DATA_ATTRIBUTE_PREFIX: &str = "data-";
+
+        if let Some(attribute) = self.get_tag_attribute(attribute, tag) {
+            return Some(attribute);
+        }
+
+        if !tag.global_attributes {
+            return None;
+        }
+
+        if attribute.starts_with(DATA_ATTRIBUTE_PREFIX) {
+            self.attributes.get(DATA_ATTRIBUTE)?.get(GLOBAL)
+        } else {
+            self.attributes.get(attribute)?.get(GLOBAL)
+        }
+    }
+
     ///
     #[must_use]
     pub fn get_tag_attributes(&self, tag: &Tag) -> Option<Vec<&Attribute>> {
@@ -121,4 +206,12 @@ impl WebReference {
                 .collect()
         )
     }
-}
\ No newline at end of file
+}
+
+// the two letter region of a language tag, after its optional extended language and script subtags
+fn language_region(tag: &str) -> Option<&str> {
+    tag.split('-')
+        .skip(1)
+        .find(|subtag| !matches!(subtag.len(), 3 | 4) || !subtag.chars().all(|chr| chr.is_ascii_alphabetic()))
+        .filter(|subtag| subtag.len() == 2 && subtag.chars().all(|chr| chr.is_ascii_alphabetic()))
+}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+impl WebReference {
+    ///
+    #[must_use]
+    pub fn get_aria_role(&self, role: &str) -> Option<&AriaRole> {
+        self.aria_roles.get(role)
+    }
+
+    ///
+    #[must_use]
+    pub fn get_aria_attribute(&self, attribute: &str) -> Option<&AriaAttribute> {
+        self.aria_attributes.get(attribute)
+    }
+
+    /// the role of a tag without a `role` attribute, i.e. `navigation` for `<nav>`
+    #[must_use]
+    pub fn get_implicit_role(&self, tag: &Tag) -> Option<&AriaRole> {
+        self.get_aria_role(tag.aria.implicit_role.as_deref()?)
+    }
+
+    /// the role a `role` attribute resolves to, the first of its tokens that is a known concrete role
+    #[must_use]
+    pub fn get_explicit_role(&self, value: &str) -> Option<&AriaRole> {
+        value.split_ascii_whitespace()
+            .filter_map(|role| self.get_aria_role(&role.to_ascii_lowercase()))
+            .find(|role| !role.is_abstract)
+    }
+
+    ///
+    #[must_use]
+    pub fn is_allowed_role(&self, tag: &Tag, role: &str) -> bool {
+        tag.aria.allowed_roles.allows(role) || tag.aria.implicit_role.as_deref() == Some(role)
+    }
+
+    /// whether a role supports a state or property, either its own, one of its superclasses' or a global one
+    #[must_use]
+    pub fn is_supported_aria_attribute(&self, role: &AriaRole, attribute: &str) -> bool {
+        if self.get_aria_attribute(attribute).is_some_and(|attribute| attribute.global) {
+            return true;
+        }
+
+        let mut pending = vec![role];
+        let mut visited = HasAriaRoles::default();
+
+        while let Some(role) = pending.pop() {
+            if role.required_attributes.contains(attribute) || role.supported_attributes.contains(attribute) {
+                return true;
+            }
+
+            for superclass in &role.superclasses {
+                if visited.insert(superclass.clone()) {
+                    pending.extend(self.get_aria_role(superclass));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// the states and properties a role requires, i.e. `aria-checked` for `checkbox`
+    #[must_use]
+    pub fn get_required_aria_attributes(&self, role: &AriaRole) -> Option<Vec<&AriaAttribute>> {
+        Some(
+            role.required_attributes.iter()
+                .filter_map(|attribute| self.get_aria_attribute(attribute))
+                .collect()
+        )
+    }
+}
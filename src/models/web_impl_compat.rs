@@ -0,0 +1,87 @@
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+
+impl WebReference {
+    ///
+    #[must_use]
+    pub fn is_attribute_supported(&self, attribute: &Attribute, tag: &Tag, targets: &BrowserTargets) -> bool {
+        self.attribute_browsers_supported(attribute, tag)
+            .is_none_or(|supported| targets.is_supported(supported))
+    }
+
+    ///
+    #[must_use]
+    pub fn is_event_supported(&self, event: &str, targets: &BrowserTargets) -> bool {
+        let event = if event.starts_with(EVENT_PREFIX) {
+            self.get_event(event)
+        } else {
+            self.get_event(&format!("{EVENT_PREFIX}{event}"))
+        };
+
+        event.is_some_and(|event| targets.is_supported(&event.supported.supported))
+    }
+
+    ///
+    #[must_use]
+    pub fn is_tag_supported(&self, tag: &Tag, targets: &BrowserTargets) -> bool {
+        self.tag_browsers_supported(tag)
+            .is_none_or(|supported| targets.is_supported(supported))
+    }
+
+    ///
+    #[must_use]
+    pub fn unsupported_attributes_of(&self, tag: &Tag, targets: &BrowserTargets) -> Vec<&Attribute> {
+        let tag_attributes = tag.attributes.iter()
+            .chain(tag.optional_attributes.iter())
+            .filter_map(|attribute| self.get_tag_attribute(attribute, tag));
+
+        let global_attributes = self.get_global_attributes(tag);
+
+        tag_attributes.chain(global_attributes)
+            .filter(|attribute| !self.is_attribute_supported(attribute, tag, targets))
+            .collect()
+    }
+
+    ///
+    #[must_use]
+    pub fn unsupported_events(&self, targets: &BrowserTargets) -> Vec<&Event> {
+        self.events.values()
+            .filter(|event| !targets.is_supported(&event.supported.supported))
+            .collect()
+    }
+
+    ///
+    #[must_use]
+    pub fn unsupported_tags(&self, targets: &BrowserTargets) -> Vec<&Tag> {
+        self.tags.values()
+            .filter(|tag| !self.is_tag_supported(tag, targets))
+            .collect()
+    }
+
+    fn attribute_browsers_supported<'a>(&'a self, attribute: &'a Attribute, tag: &Tag) -> Option<&'a BrowsersSupported> {
+        if attribute.supported.supported.is_empty() {
+            self.supported_browsers.get(&tag.name)?
+                .attributes.get(&attribute.name)
+        } else {
+            Some(&attribute.supported.supported)
+        }
+    }
+
+    fn get_global_attributes(&self, tag: &Tag) -> Vec<&Attribute> {
+        if tag.global_attributes {
+            self.get_attributes_of_category(AttributeCategory::GlobalAttributes)
+                .unwrap_or_default()
+        } else {
+            vec![]
+        }
+    }
+
+    fn tag_browsers_supported<'a>(&'a self, tag: &'a Tag) -> Option<&'a BrowsersSupported> {
+        if tag.supported.supported.is_empty() {
+            self.supported_browsers.get(&tag.name)
+                .map(|supported| &supported.browsers.supported)
+        } else {
+            Some(&tag.supported.supported)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+impl WebReference {
+    /// whether a tag may be a child of another, i.e. `<li>` of `<ul>` but not `<div>` of `<p>`, tags without
+    /// a content model, i.e. obsolete tags, may contain and be contained by any tag
+    ///
+    /// the check is context free, a tag with permitted parents may be a child of any of them wherever that
+    /// parent is, i.e. `<dt>` of a `<div>` that is not itself in a `<dl>`
+    ///
+    /// ```
+    /// # #[cfg(feature = "embedded-specs")] {
+    /// # use web_reference::prelude::*;
+    /// let reference = WebReference::embedded();
+    ///
+    /// assert!(reference.can_contain("ul", "li"));
+    /// assert!(!reference.can_contain("div", "li"));
+    /// assert!(reference.can_contain("div", "dt"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn can_contain(&self, parent: &str, child: &str) -> bool {
+        let (Some(parent), Some(child)) = (self.get_tag(parent), self.get_tag(child)) else {
+            return false;
+        };
+
+        let (Some(parent_model), Some(child_model)) = (&parent.content_model, &child.content_model) else {
+            return true;
+        };
+
+        if parent_model.content == PermittedContent::Any {
+            return true;
+        }
+
+        // permitted parents decide on their own, i.e. `<dt>` of `<div>` whatever the content of `<div>`
+        if !child_model.parents.is_empty() {
+            return child_model.parents.contains(&parent.name);
+        }
+
+        parent_model.permits(&child.name, child_model)
+    }
+
+    /// whether a tag may contain text, i.e. `<p>` but not `<ul>`
+    #[must_use]
+    pub fn can_contain_text(&self, tag: &str) -> bool {
+        let Some(tag) = self.get_tag(tag) else {
+            return false;
+        };
+
+        tag.content_model.as_ref().is_none_or(|model| match model.content {
+                PermittedContent::Nothing => false,
+                PermittedContent::Any | PermittedContent::Text | PermittedContent::Transparent => true,
+                PermittedContent::Elements => model.content_categories.contains(&ContentCategory::Phrasing) ||
+                    model.content_categories.contains(&ContentCategory::Flow),
+            })
+    }
+
+    /// tags of a content category, i.e. `<h1>` to `<h6>` of `heading`
+    #[must_use]
+    pub fn get_tags_of_content_category(&self, category: ContentCategory) -> Vec<&Tag> {
+        self.tags.values()
+            .filter(|tag| tag.content_model.as_ref().is_some_and(|model| model.is_in_category(category)))
+            .collect()
+    }
+}
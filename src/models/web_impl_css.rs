@@ -0,0 +1,48 @@
+use crate::prelude::*;
+
+impl WebReference {
+    ///
+    #[must_use]
+    pub fn get_css_property(&self, property: &str) -> Option<&CssProperty> {
+        self.css_properties.get(property)
+    }
+
+    ///
+    #[must_use]
+    pub fn get_css_category(&self, category: CssCategory) -> Option<&CssCategoryDetails> {
+        self.css_categorized.get(&category)
+    }
+
+    ///
+    #[must_use]
+    pub fn get_css_properties_of_category(&self, category: CssCategory) -> Option<Vec<&CssProperty>> {
+        Some(
+            self.css_categorized.get(&category)?
+                .properties.iter()
+                .filter_map(|property| self.get_css_property(property))
+                .collect()
+        )
+    }
+
+    /// css properties that replace an obsolete presentational attribute, i.e. `background-color` for `bgcolor`
+    #[must_use]
+    pub fn get_css_alternatives(&self, attribute: &str) -> Vec<&CssProperty> {
+        self.css_properties.values()
+            .filter(|property| property.presentational_attributes.contains(attribute))
+            .collect()
+    }
+
+    /// css properties that replace an obsolete tag, i.e. `text-align` for `<center>`
+    #[must_use]
+    pub fn get_tag_css_alternatives(&self, tag: &Tag) -> Option<Vec<&CssProperty>> {
+        let TagAlternatives::Css(properties) = &tag.alternatives else {
+            return None;
+        };
+
+        Some(
+            properties.iter()
+                .filter_map(|property| self.get_css_property(property))
+                .collect()
+        )
+    }
+}
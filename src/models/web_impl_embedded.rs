@@ -0,0 +1,37 @@
+use std::sync::OnceLock;
+
+use crate::prelude::*;
+
+const EMBEDDED_SPECS: &str = include_str!("../../html-5-specs.json");
+
+static EMBEDDED_REFERENCE: OnceLock<WebReference> = OnceLock::new();
+static EMBEDDED_INTERNED: OnceLock<InternedReference<'static>> = OnceLock::new();
+
+impl WebReference {
+    /// # Panics
+    ///
+    /// if the `html-5-specs.json` embedded at compile time is not a valid reference
+    #[must_use]
+    pub fn embedded() -> &'static Self {
+        EMBEDDED_REFERENCE.get_or_init(|| {
+            Self::load_from_str(EMBEDDED_SPECS)
+                .expect("expect embedded html-5-specs.json to be a valid reference")
+        })
+    }
+
+    /// the embedded reference interned once, see [`WebReference::interned`]
+    ///
+    /// # Panics
+    ///
+    /// if the `html-5-specs.json` embedded at compile time is not a valid reference
+    #[must_use]
+    pub fn embedded_interned() -> &'static InternedReference<'static> {
+        EMBEDDED_INTERNED.get_or_init(|| Self::embedded().interned())
+    }
+
+    ///
+    #[must_use]
+    pub const fn embedded_json() -> &'static str {
+        EMBEDDED_SPECS
+    }
+}
@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+impl WebReference {
+    /// interns the tags, attributes and events of the reference, resolving their cross references once
+    #[must_use]
+    pub fn interned(&self) -> InternedReference<'_> {
+        InternedReference::new(self)
+    }
+}
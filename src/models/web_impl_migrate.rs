@@ -0,0 +1,148 @@
+use std::io;
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+
+use crate::prelude::*;
+
+const FORMAT_VERSION_KEY: &str = "format-version";
+
+// upgrades the json of a reference by one version, `MIGRATIONS[n]` from version `n` to `n + 1`
+const MIGRATIONS: [fn(&mut Value); FORMAT_VERSION as usize] = [
+    migrate_raw_versions_and_css_alternatives,
+];
+
+impl WebReference {
+    /// the json of a reference migrated to the current [`FORMAT_VERSION`]
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the json is of a newer format version or is not a valid reference once migrated
+    pub fn load_from_value(mut specs: Value) -> io::Result<Self> {
+        migrate(&mut specs)?;
+
+        serde_json::from_value(specs)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+    }
+}
+
+fn migrate(specs: &mut Value) -> io::Result<()> {
+    let version = specs.get(FORMAT_VERSION_KEY).map_or(Some(0), Value::as_u64)
+        .and_then(|version| usize::try_from(version).ok())
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, format!("{FORMAT_VERSION_KEY} is not a valid version")))?;
+
+    if version > MIGRATIONS.len() {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{FORMAT_VERSION_KEY} {version} is newer than the supported {FORMAT_VERSION}"),
+        ));
+    }
+
+    // maps serialize as sequences of key value pairs with `ordered-map` and `serde_json`, in any version
+    sequences_to_maps(specs);
+
+    for migration in &MIGRATIONS[version..] {
+        migration(specs);
+    }
+
+    if let Some(specs) = specs.as_object_mut() {
+        specs.insert(FORMAT_VERSION_KEY.to_string(), json!(FORMAT_VERSION));
+    }
+
+    Ok(())
+}
+
+// i.e. `[["chrome", "yes"], ["edge", "no"]]` into `{"chrome": "yes", "edge": "no"}`, sets are sequences of
+// names and never of pairs
+fn sequences_to_maps(value: &mut Value) {
+    match value {
+        Value::Array(items) => {
+            items.iter_mut().for_each(sequences_to_maps);
+
+            let is_pairs = !items.is_empty() && items.iter().all(|item| matches!(
+                item.as_array().map(Vec::as_slice), Some([Value::String(_), _])
+            ));
+
+            if is_pairs {
+                let pairs = items.drain(..)
+                    .filter_map(|item| match item {
+                        Value::Array(mut pair) => pair.pop().zip(pair.pop()),
+                        _ => None,
+                    })
+                    .filter_map(|(value, key)| key.as_str().map(|key| (key.to_string(), value)))
+                    .collect();
+
+                *value = Value::Object(pairs);
+            }
+        }
+        Value::Object(fields) =>
+            fields.values_mut().for_each(sequences_to_maps),
+        _ => {}
+    }
+}
+
+// version 0 kept the version of browser support as scraped, i.e. `"4.0 webkit"` or `"Not supported"`, and
+// css alternatives without the css properties
+fn migrate_raw_versions_and_css_alternatives(specs: &mut Value) {
+    raw_versions_to_support(specs);
+
+    for tags in ["tags", "svg-tags"] {
+        let Some(tags) = specs.get_mut(tags).and_then(Value::as_object_mut) else {
+            continue;
+        };
+
+        for tag in tags.values_mut() {
+            if let Some(alternatives) = tag.get_mut("alternatives").filter(|alternatives| *alternatives == "css") {
+                *alternatives = json!({ "css": [] });
+            }
+        }
+    }
+}
+
+// `{"version": {"version": "4.0 webkit", "caveat": 0}}` is the only shape of a raw version, a current version
+// of that shape parses as itself
+fn raw_versions_to_support(value: &mut Value) {
+    let has_fields = |value: &Value, count: usize| value.as_object().is_some_and(|fields| fields.len() == count);
+
+    let raw = value.get("version")
+        .filter(|version| has_fields(value, 1) && has_fields(version, 2))
+        .and_then(|version| Some((version.get("version")?.as_str()?, version.get("caveat")?.as_u64()?)));
+
+    if let Some((version, caveat)) = raw {
+        // the caveat counts the asterisks that marked it
+        let marked = format!("{}{}", version.trim(), "*".repeat(usize::try_from(caveat).unwrap_or_default()));
+
+        *value = support_to_json(&Support::from_str(&marked).unwrap_or(Support::Unknown));
+
+        return;
+    }
+
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(raw_versions_to_support),
+        Value::Object(fields) => fields.values_mut().for_each(raw_versions_to_support),
+        _ => {}
+    }
+}
+
+// the serialized form of a support, without requiring the `serialize` feature
+fn support_to_json(support: &Support) -> Value {
+    match support {
+        Support::Yes => json!("yes"),
+        Support::No => json!("no"),
+        Support::Unknown => json!("unknown"),
+        Support::Version { version, prefix, unprefixed, caveat } => {
+            let mut details = json!({ "version": version.to_string(), "caveat": caveat });
+
+            if let Some(prefix) = prefix {
+                details["prefix"] = json!(prefix.to_string().trim_matches('-'));
+            }
+
+            if let Some(unprefixed) = unprefixed {
+                details["unprefixed"] = json!(unprefixed.to_string());
+            }
+
+            json!({ "version": details })
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+const GLOBAL: &str = "global";
+
+impl WebReference {
+    /// the tags of a namespace
+    #[must_use]
+    pub const fn namespaced_tags(&self, namespace: Namespace) -> &Tags {
+        match namespace {
+            Namespace::Html => &self.tags,
+            Namespace::Svg => &self.svg_tags,
+        }
+    }
+
+    /// the attributes of a namespace
+    #[must_use]
+    pub const fn namespaced_attributes(&self, namespace: Namespace) -> &Attributes {
+        match namespace {
+            Namespace::Html => &self.attributes,
+            Namespace::Svg => &self.svg_attributes,
+        }
+    }
+
+    ///
+    #[must_use]
+    pub const fn namespaced_attributes_categorized(&self, namespace: Namespace) -> &AttributesCategorized {
+        match namespace {
+            Namespace::Html => &self.attributes_categorized,
+            Namespace::Svg => &self.svg_attributes_categorized,
+        }
+    }
+
+    ///
+    #[must_use]
+    pub fn is_valid_namespaced_tag(&self, namespace: Namespace, tag: &str) -> bool {
+        self.get_namespaced_tag(namespace, tag).is_some()
+    }
+
+    ///
+    #[must_use]
+    pub fn is_valid_namespaced_attribute(&self, namespace: Namespace, attribute: &str) -> bool {
+        namespaced_entry(self.namespaced_attributes(namespace), attribute).is_some()
+    }
+
+    /// a tag in the context of a namespace, svg tags match case insensitively as html parsers lowercase them,
+    /// i.e. `lineargradient` is `linearGradient`
+    #[must_use]
+    pub fn get_namespaced_tag(&self, namespace: Namespace, tag: &str) -> Option<&Tag> {
+        match namespace {
+            Namespace::Html => self.get_tag(tag),
+            Namespace::Svg => namespaced_entry(&self.svg_tags, tag),
+        }
+    }
+
+    /// an attribute of a tag, or a global attribute of the namespace, in the context of a namespace
+    #[must_use]
+    pub fn find_namespaced_tag_attribute(&self, namespace: Namespace, attribute: &str, tag: &Tag) -> Option<&Attribute> {
+        match namespace {
+            Namespace::Html => self.find_tag_attribute(attribute, tag),
+            Namespace::Svg => {
+                let tags = namespaced_entry(&self.svg_attributes, attribute)?;
+
+                tags.get(&tag.name)
+                    .or_else(|| tags.get(GLOBAL).filter(|_| tag.global_attributes))
+            }
+        }
+    }
+}
+
+// an exact match, or the case insensitive match of a name lowercased by an html parser
+fn namespaced_entry<'a, V>(entries: &'a Map<String, V>, name: &str) -> Option<&'a V> {
+    entries.get(name)
+        .or_else(|| entries.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value))
+}
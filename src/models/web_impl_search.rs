@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+impl WebReference {
+    /// ranked hits of tags, attributes, values and events matching `query`, best match first
+    ///
+    /// builds a new index on every call, use [`WebReference::search_index`] to run several queries
+    ///
+    /// ```
+    /// # #[cfg(feature = "embedded-specs")] {
+    /// # use web_reference::prelude::*;
+    /// let hits = WebReference::embedded().search("which element lets the user view or hide details");
+    ///
+    /// assert_eq!((hits[0].kind, hits[0].name.as_str()), (SearchKind::Tag, "details"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        self.search_index().search(query)
+    }
+
+    ///
+    #[must_use]
+    pub fn search_index(&self) -> SearchIndex<'_> {
+        SearchIndex::new(self)
+    }
+}
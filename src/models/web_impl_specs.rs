@@ -1,20 +1,36 @@
 use std::env::current_dir;
 use std::fs::File;
 use std::io;
-use std::io::{BufReader, ErrorKind};
-use std::path::PathBuf;
+use std::io::{BufReader, ErrorKind, Read};
+use std::path::{Path, PathBuf};
 
 use crate::prelude::*;
 
 impl WebReference {
     /// # Errors
     pub fn load_specs() -> io::Result<Self> {
-        let specs_path = find_specs_file()?;
+        Self::load_from_path(find_specs_file()?)
+    }
+
+    /// # Errors
+    pub fn load_from_path<P: AsRef<Path>>(specs_path: P) -> io::Result<Self> {
         let input = File::open(specs_path)?;
-        let reader = BufReader::new(input);
 
+        Self::load_from_reader(BufReader::new(input))
+    }
+
+    /// # Errors
+    pub fn load_from_reader<R: Read>(reader: R) -> io::Result<Self> {
         serde_json::from_reader(reader)
             .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+            .and_then(Self::load_from_value)
+    }
+
+    /// # Errors
+    pub fn load_from_str(specs: &str) -> io::Result<Self> {
+        serde_json::from_str(specs)
+            .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+            .and_then(Self::load_from_value)
     }
 }
 
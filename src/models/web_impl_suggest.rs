@@ -0,0 +1,156 @@
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+
+const GLOBAL: &str = "global";
+const MAX_SUGGESTIONS: usize = 5;
+
+impl WebReference {
+    /// attributes valid on `tag` that closely match a misspelled `attribute`, best match first
+    #[must_use]
+    pub fn suggest_attribute(&self, tag: &str, attribute: &str) -> Vec<&str> {
+        let Some(tag) = self.get_tag(tag) else {
+            return Vec::new();
+        };
+
+        let attribute = attribute.to_ascii_lowercase();
+
+        let specific = tag.attributes.iter().chain(tag.optional_attributes.iter())
+            .map(String::as_str);
+
+        let global = self.attributes.iter()
+            .filter(|(name, tags)| tag.global_attributes && !name.contains('*') && tags.contains_key(GLOBAL))
+            .map(|(name, _)| name.as_str());
+
+        // event handler attributes are only suggested for event handler like misspellings
+        let events = self.events.keys()
+            .filter(|_| tag.global_events && attribute.starts_with(EVENT_PREFIX))
+            .map(String::as_str);
+
+        rank(&attribute, specific.chain(global).chain(events))
+    }
+
+    /// attributes of any tag that closely match a misspelled `attribute`, best match first
+    #[must_use]
+    pub fn suggest_any_attribute(&self, attribute: &str) -> Vec<&str> {
+        let attribute = attribute.to_ascii_lowercase();
+
+        let attributes = self.attributes.keys()
+            .filter(|name| !name.contains('*'))
+            .map(String::as_str);
+
+        // event handler attributes are only suggested for event handler like misspellings
+        let events = self.events.keys()
+            .filter(|_| attribute.starts_with(EVENT_PREFIX))
+            .map(String::as_str);
+
+        rank(&attribute, attributes.chain(events))
+    }
+
+    /// events that closely match a misspelled `event`, with or without the `on` prefix, best match first
+    #[must_use]
+    pub fn suggest_event(&self, event: &str) -> Vec<&str> {
+        let event = event.to_ascii_lowercase();
+        let event = event.strip_prefix(EVENT_PREFIX).unwrap_or(&event);
+
+        let mut ranked = self.events.keys()
+            .filter_map(|name| {
+                let score = score(event, name.strip_prefix(EVENT_PREFIX).unwrap_or(name))?;
+
+                Some((score, name.as_str()))
+            })
+            .collect::<Vec<_>>();
+
+        ranked.sort_unstable();
+
+        ranked.into_iter().take(MAX_SUGGESTIONS).map(|(_, name)| name).collect()
+    }
+
+    /// tags that closely match a misspelled `tag`, best match first
+    #[must_use]
+    pub fn suggest_tag(&self, tag: &str) -> Vec<&str> {
+        let tag = tag.trim_start_matches('<').trim_end_matches('>').to_ascii_lowercase();
+
+        rank(&tag, self.tags.keys().map(String::as_str))
+    }
+}
+
+/// a hint with the best of `suggestions`, if any, i.e. `, did you mean "href"?`
+#[must_use]
+pub fn did_you_mean(suggestions: impl IntoIterator<Item=String>) -> String {
+    suggestions.into_iter().next()
+        .map(|suggestion| format!(", did you mean {suggestion}?"))
+        .unwrap_or_default()
+}
+
+fn rank<'a>(query: &str, candidates: impl Iterator<Item=&'a str>) -> Vec<&'a str> {
+    let mut ranked = candidates
+        .filter_map(|candidate| Some((score(query, candidate)?, candidate)))
+        .collect::<Vec<_>>();
+
+    ranked.sort_unstable();
+    ranked.dedup();
+
+    ranked.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate).collect()
+}
+
+// lower scores are better matches, none if the candidate is too far off to be a likely typo
+fn score(query: &str, candidate: &str) -> Option<(usize, bool)> {
+    if query.is_empty() || query == candidate {
+        return None;
+    }
+
+    // separators are commonly mistyped or left out, i.e. `accept_charset` or `acceptcharset`
+    let normalize = |name: &str| name.chars().filter(|chr| *chr != '-' && *chr != '_').collect::<String>();
+
+    let (query_normalized, candidate_normalized) = (normalize(query), normalize(candidate));
+    let distance = edit_distance(&query_normalized, &candidate_normalized);
+
+    // the first letter is rarely the one mistyped, prefer candidates that share it
+    let different_start = query.chars().next() != candidate.chars().next();
+
+    if distance == 0 {
+        return Some((0, different_start));
+    }
+
+    let threshold = query_normalized.chars().count().max(candidate_normalized.chars().count()) / 3;
+
+    if distance <= threshold.max(1) {
+        Some((distance, different_start))
+    } else if query_normalized.len() >= 3 && candidate_normalized.starts_with(&query_normalized) {
+        // an abbreviated name, i.e. `autocomp`, ranks behind actual misspellings
+        Some((threshold.max(1) + 1, different_start))
+    } else {
+        None
+    }
+}
+
+// optimal string alignment distance, a levenshtein distance that counts adjacent transpositions,
+// i.e. `herf` for `href`, as a single edit
+fn edit_distance(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<_>>();
+    let target = target.chars().collect::<Vec<_>>();
+
+    let mut previous = Vec::<usize>::new();
+    let mut current = (0..=target.len()).collect::<Vec<_>>();
+
+    for idx in 1..=source.len() {
+        let before = std::mem::replace(&mut previous, std::mem::take(&mut current));
+
+        current = vec![0; target.len() + 1];
+        current[0] = idx;
+
+        for jdx in 1..=target.len() {
+            let cost = usize::from(source[idx - 1] != target[jdx - 1]);
+
+            current[jdx] = (previous[jdx] + 1)
+                .min(current[jdx - 1] + 1)
+                .min(previous[jdx - 1] + cost);
+
+            if idx > 1 && jdx > 1 && source[idx - 1] == target[jdx - 2] && source[idx - 2] == target[jdx - 1] {
+                current[jdx] = current[jdx].min(before[jdx - 2] + 1);
+            }
+        }
+    }
+
+    current[target.len()]
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+impl WebReference {
+    /// the element kind of a tag, i.e. [`ElementKind::Void`] of `<br>`
+    #[must_use]
+    pub fn get_element_kind(&self, tag: &str) -> Option<ElementKind> {
+        self.get_tag(tag).map(|tag| tag.syntax.kind)
+    }
+
+    /// whether a tag has neither content nor an end tag, i.e. `<br>` and `<img>`
+    #[must_use]
+    pub fn is_void_tag(&self, tag: &str) -> bool {
+        self.get_element_kind(tag) == Some(ElementKind::Void)
+    }
+
+    /// whether a tag contains text that must be written verbatim, i.e. `<script>` and `<style>`
+    #[must_use]
+    pub fn is_raw_text_tag(&self, tag: &str) -> bool {
+        self.get_element_kind(tag) == Some(ElementKind::RawText)
+    }
+
+    /// whether a tag contains text with character references but no tags, i.e. `<textarea>` and `<title>`
+    #[must_use]
+    pub fn is_escapable_raw_text_tag(&self, tag: &str) -> bool {
+        self.get_element_kind(tag) == Some(ElementKind::EscapableRawText)
+    }
+
+    /// whether the start tag of an element may be omitted given the first node inside it, i.e. `<tbody>` before `<tr>`
+    #[must_use]
+    pub fn can_omit_start_tag(&self, tag: &str, first: NextNode<'_>) -> bool {
+        self.get_tag(tag)
+            .and_then(|tag| tag.syntax.start_tag_omission.as_ref())
+            .is_some_and(|rule| rule.allows(first, None))
+    }
+
+    /// whether the end tag of an element may be omitted given the node after it and its parent, i.e. `</li>`
+    /// before `<li>`, void tags have no end tag to omit
+    #[must_use]
+    pub fn can_omit_end_tag(&self, tag: &str, next: NextNode<'_>, parent: Option<&str>) -> bool {
+        self.get_tag(tag)
+            .and_then(|tag| tag.syntax.end_tag_omission.as_ref())
+            .is_some_and(|rule| rule.allows(next, parent))
+    }
+
+    /// tags of an element kind, i.e. `<script>` and `<style>` of [`ElementKind::RawText`]
+    #[must_use]
+    pub fn get_tags_of_element_kind(&self, kind: ElementKind) -> Vec<&Tag> {
+        self.tags.values()
+            .filter(|tag| tag.syntax.kind == kind)
+            .collect()
+    }
+}
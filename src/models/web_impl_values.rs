@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+impl WebReference {
+    /// # Errors
+    pub fn validate_attribute_value(&self, tag: &str, attribute: &str, value: &str) -> Result<(), ValueError> {
+        let tag_details = self.get_tag(tag)
+            .ok_or_else(|| ValueError::UnknownTag { tag: tag.to_string() })?;
+
+        let attribute_details = self.find_tag_attribute(attribute, tag_details)
+            .ok_or_else(|| ValueError::UnknownAttribute { tag: tag.to_string(), attribute: attribute.to_string() })?;
+
+        self.validate_value(attribute, attribute_details, value)
+    }
+
+    /// validates a value's syntax, with language codes also looked up in the reference's languages and countries
+    ///
+    /// # Errors
+    pub fn validate_value(&self, attribute: &str, details: &Attribute, value: &str) -> Result<(), ValueError> {
+        details.values.validate(attribute, value)?;
+
+        if !matches!(details.values, AttributeValue::LanguageCode { .. }) || value.is_empty() {
+            return Ok(());
+        }
+
+        if !self.is_valid_language(value) {
+            return Err(ValueError::UnknownLanguage {
+                attribute: attribute.to_string(),
+                value: value.to_string(),
+            });
+        }
+
+        if !self.is_valid_language_region(value) {
+            return Err(ValueError::UnknownCountry {
+                attribute: attribute.to_string(),
+                value: value.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,35 @@
 //!
 
+pub use super::models::aria::*;
 pub use super::models::attr::*;
+pub use super::models::attr_value::*;
 pub use super::models::browser::*;
 pub use super::models::common::*;
+pub use super::models::content::*;
+pub use super::models::country::*;
+pub use super::models::css::*;
 pub use super::models::event::*;
+pub use super::models::interned::*;
+pub use super::models::lang::*;
+pub use super::models::search::*;
+pub use super::models::syntax::*;
 pub use super::models::tag::*;
 pub use super::models::types::*;
 pub use super::models::web::*;
+pub use super::models::web_impl_suggest::*;
+#[cfg(feature = "binary")]
+pub use super::binary::error::*;
+#[cfg(feature = "binary")]
+pub use super::binary::view::*;
+#[cfg(feature = "diff")]
+pub use super::diff::changelog::*;
+pub use super::store::reference_store::*;
+#[cfg(feature = "sqlite")]
+pub use super::store::sqlite_store::*;
+#[cfg(feature = "validate")]
+pub use super::validate::diagnostic::*;
+#[cfg(feature = "validate")]
+pub use super::validate::span::*;
+#[cfg(feature = "validate")]
+pub use super::validate::validate_impl::*;
 
@@ -0,0 +1,7 @@
+//!
+
+pub mod reference_store;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_export;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_store;
@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::error::Error;
+
+use crate::prelude::*;
+
+/// lookups shared by an in memory [`WebReference`] and a database backed store
+pub trait ReferenceStore {
+    ///
+    type Error: Error;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the attribute
+    fn is_valid_attribute(&self, attribute: &str) -> Result<bool, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the event
+    fn is_valid_event(&self, event: &str) -> Result<bool, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the tag
+    fn is_valid_tag(&self, tag: &str) -> Result<bool, Self::Error>;
+
+    /// an attribute as it is defined for a tag, or `global` for global attributes
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the attribute
+    fn get_attribute(&self, attribute: &str, tag: &str) -> Result<Option<Cow<'_, Attribute>>, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the event
+    fn get_event(&self, event: &str) -> Result<Option<Cow<'_, Event>>, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the events
+    fn get_events_of_category(&self, category: EventCategory) -> Result<Vec<Cow<'_, Event>>, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the tag
+    fn get_tag(&self, tag: &str) -> Result<Option<Cow<'_, Tag>>, Self::Error>;
+
+    /// the required and optional attributes defined for a tag, without global attributes
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the attributes
+    fn get_tag_attributes(&self, tag: &str) -> Result<Vec<Cow<'_, Attribute>>, Self::Error>;
+
+    /// # Errors
+    ///
+    /// Will return `Err` if the store fails to look up the tags
+    fn get_tags_of_category(&self, category: TagCategory) -> Result<Vec<Cow<'_, Tag>>, Self::Error>;
+}
+
+impl ReferenceStore for WebReference {
+    type Error = Infallible;
+
+    fn is_valid_attribute(&self, attribute: &str) -> Result<bool, Self::Error> {
+        Ok(Self::is_valid_attribute(self, attribute))
+    }
+
+    fn is_valid_event(&self, event: &str) -> Result<bool, Self::Error> {
+        Ok(Self::is_valid_event(self, event))
+    }
+
+    fn is_valid_tag(&self, tag: &str) -> Result<bool, Self::Error> {
+        Ok(Self::is_valid_tag(self, tag))
+    }
+
+    fn get_attribute(&self, attribute: &str, tag: &str) -> Result<Option<Cow<'_, Attribute>>, Self::Error> {
+        Ok(self.attributes.get(attribute).and_then(|tags| tags.get(tag)).map(Cow::Borrowed))
+    }
+
+    fn get_event(&self, event: &str) -> Result<Option<Cow<'_, Event>>, Self::Error> {
+        Ok(Self::get_event(self, event).map(Cow::Borrowed))
+    }
+
+    fn get_events_of_category(&self, category: EventCategory) -> Result<Vec<Cow<'_, Event>>, Self::Error> {
+        Ok(
+            Self::get_events_of_category(self, category).unwrap_or_default()
+                .into_iter().map(Cow::Borrowed).collect()
+        )
+    }
+
+    fn get_tag(&self, tag: &str) -> Result<Option<Cow<'_, Tag>>, Self::Error> {
+        Ok(Self::get_tag(self, tag).map(Cow::Borrowed))
+    }
+
+    fn get_tag_attributes(&self, tag: &str) -> Result<Vec<Cow<'_, Attribute>>, Self::Error> {
+        let Some(tag) = Self::get_tag(self, tag) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(
+            tag.attributes.iter()
+                .chain(tag.optional_attributes.iter())
+                .filter_map(|attribute| self.get_tag_attribute(attribute, tag))
+                .map(Cow::Borrowed)
+                .collect()
+        )
+    }
+
+    fn get_tags_of_category(&self, category: TagCategory) -> Result<Vec<Cow<'_, Tag>>, Self::Error> {
+        Ok(
+            Self::get_tags_of_category(self, category).unwrap_or_default()
+                .into_iter().map(Cow::Borrowed).collect()
+        )
+    }
+}
@@ -0,0 +1,163 @@
+-- normalized schema of a web reference, rows are inserted in the reference's order
+
+CREATE TABLE tags (
+    name              TEXT    NOT NULL PRIMARY KEY,
+    description       TEXT    NOT NULL,
+    global_attributes INTEGER NOT NULL,
+    global_events     INTEGER NOT NULL,
+    -- 'none', 'css' or 'tags', see tag_alternatives for the css properties or tags
+    alternatives      TEXT    NOT NULL,
+    implicit_role     TEXT,
+    -- 'any', 'none' or 'roles', see tag_allowed_roles for the roles
+    allowed_roles     TEXT    NOT NULL,
+    -- 'any', 'nothing', 'text', 'transparent' or 'elements', null when the content model is unknown,
+    -- see tag_content_model
+    content           TEXT,
+    -- 'void', 'template', 'rawtext', 'escapablerawtext', 'foreign' or 'normal'
+    kind              TEXT    NOT NULL
+);
+
+CREATE TABLE tag_alternatives (
+    tag         TEXT NOT NULL REFERENCES tags (name),
+    alternative TEXT NOT NULL,
+    PRIMARY KEY (tag, alternative)
+);
+
+CREATE TABLE tag_allowed_roles (
+    tag  TEXT NOT NULL REFERENCES tags (name),
+    role TEXT NOT NULL,
+    PRIMARY KEY (tag, role)
+);
+
+-- the content categories and tags of a tag's content model
+CREATE TABLE tag_content_model (
+    tag  TEXT NOT NULL REFERENCES tags (name),
+    -- 'category', 'content-category', 'content-tag', 'excluded-category', 'excluded-tag' or 'parent'
+    kind TEXT NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (tag, kind, name)
+);
+
+-- when a start or end tag may be omitted, see tag_omission_tags
+CREATE TABLE tag_omissions (
+    tag                      TEXT    NOT NULL REFERENCES tags (name),
+    -- 'start' or 'end'
+    omits                    TEXT    NOT NULL,
+    description              TEXT    NOT NULL,
+    when_empty               INTEGER NOT NULL,
+    at_parent_end            INTEGER NOT NULL,
+    next_element             INTEGER NOT NULL,
+    excluded_next_comment    INTEGER NOT NULL,
+    excluded_next_whitespace INTEGER NOT NULL,
+    PRIMARY KEY (tag, omits)
+);
+
+-- the tags of an omission's conditions
+CREATE TABLE tag_omission_tags (
+    tag   TEXT NOT NULL REFERENCES tags (name),
+    omits TEXT NOT NULL,
+    -- 'excluded-parent', 'next' or 'excluded-next'
+    kind  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    PRIMARY KEY (tag, omits, kind, name)
+);
+
+-- tag is the name of the tag an attribute is defined for, or 'global'
+CREATE TABLE attributes (
+    name              TEXT NOT NULL,
+    tag               TEXT NOT NULL,
+    description       TEXT NOT NULL,
+    -- 'tags', 'global' or 'not-supported', see attribute_belongs_to
+    belongs_to        TEXT NOT NULL,
+    -- the kind of attribute value, see attribute_values for 'Values'
+    value_kind        TEXT NOT NULL,
+    value_description TEXT,
+    PRIMARY KEY (name, tag)
+);
+
+CREATE TABLE attribute_belongs_to (
+    attribute TEXT NOT NULL,
+    tag       TEXT NOT NULL,
+    belongs   TEXT NOT NULL,
+    PRIMARY KEY (attribute, tag, belongs)
+);
+
+CREATE TABLE attribute_values (
+    attribute   TEXT NOT NULL,
+    tag         TEXT NOT NULL,
+    -- the keyword as it is keyed in the reference, which can differ from its display name
+    key         TEXT NOT NULL,
+    name        TEXT NOT NULL,
+    description TEXT NOT NULL,
+    PRIMARY KEY (attribute, tag, key)
+);
+
+CREATE TABLE tag_attributes (
+    tag       TEXT    NOT NULL REFERENCES tags (name),
+    attribute TEXT    NOT NULL,
+    optional  INTEGER NOT NULL,
+    PRIMARY KEY (tag, attribute)
+);
+
+CREATE TABLE events (
+    name        TEXT    NOT NULL PRIMARY KEY,
+    description TEXT    NOT NULL,
+    -- 'not-defined', 'all-visible' or 'tags', see event_belongs_to
+    belongs_to  TEXT    NOT NULL,
+    bubbles     INTEGER NOT NULL,
+    cancelable  INTEGER NOT NULL
+);
+
+CREATE TABLE event_belongs_to (
+    event TEXT NOT NULL REFERENCES events (name),
+    tag   TEXT NOT NULL,
+    PRIMARY KEY (event, tag)
+);
+
+CREATE TABLE event_objects (
+    event  TEXT NOT NULL REFERENCES events (name),
+    object TEXT NOT NULL,
+    PRIMARY KEY (event, object)
+);
+
+CREATE TABLE event_deprecated (
+    event       TEXT NOT NULL REFERENCES events (name),
+    alternative TEXT NOT NULL,
+    PRIMARY KEY (event, alternative)
+);
+
+-- entity is 'tag', 'attribute' or 'event', tag is only set for attributes
+CREATE TABLE support (
+    entity     TEXT NOT NULL,
+    name       TEXT NOT NULL,
+    tag        TEXT NOT NULL DEFAULT '',
+    browser    TEXT NOT NULL,
+    -- 'yes', 'no', 'unknown' or 'version'
+    support    TEXT NOT NULL,
+    version    TEXT,
+    prefix     TEXT,
+    unprefixed TEXT,
+    caveat     INTEGER,
+    PRIMARY KEY (entity, name, tag, browser)
+);
+
+CREATE TABLE caveats (
+    entity TEXT NOT NULL,
+    name   TEXT NOT NULL,
+    tag    TEXT NOT NULL DEFAULT '',
+    caveat TEXT NOT NULL,
+    PRIMARY KEY (entity, name, tag, caveat)
+);
+
+-- kind is 'tag', 'attribute' or 'event'
+CREATE TABLE categories (
+    kind     TEXT NOT NULL,
+    category TEXT NOT NULL,
+    name     TEXT NOT NULL,
+    PRIMARY KEY (kind, category, name)
+);
+
+CREATE TABLE event_categories (
+    category    TEXT NOT NULL PRIMARY KEY,
+    description TEXT NOT NULL
+);
@@ -0,0 +1,328 @@
+use std::fmt::Debug;
+
+use rusqlite::{params, Connection};
+
+use crate::prelude::*;
+
+const SCHEMA: &str = include_str!("schema.sql");
+
+pub const ATTRIBUTE: &str = "attribute";
+pub const EVENT: &str = "event";
+pub const TAG: &str = "tag";
+
+// the kinds of rows of `tag_content_model`
+pub const CONTENT_CATEGORY: &str = "category";
+pub const CONTENT_CONTENT_CATEGORY: &str = "content-category";
+pub const CONTENT_CONTENT_TAG: &str = "content-tag";
+pub const CONTENT_EXCLUDED_CATEGORY: &str = "excluded-category";
+pub const CONTENT_EXCLUDED_TAG: &str = "excluded-tag";
+pub const CONTENT_PARENT: &str = "parent";
+
+// the omitted tags of `tag_omissions` and the kinds of rows of `tag_omission_tags`
+pub const OMITS_START: &str = "start";
+pub const OMITS_END: &str = "end";
+pub const OMISSION_EXCLUDED_PARENT: &str = "excluded-parent";
+pub const OMISSION_NEXT: &str = "next";
+pub const OMISSION_EXCLUDED_NEXT: &str = "excluded-next";
+
+impl WebReference {
+    /// writes the reference into a normalized schema, see `schema.sql`, of an empty database
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the schema already exists or any row fails to insert
+    pub fn export_sqlite(&self, connection: &mut Connection) -> rusqlite::Result<()> {
+        let transaction = connection.transaction()?;
+
+        transaction.execute_batch(SCHEMA)?;
+
+        export_tags(&transaction, self)?;
+        export_attributes(&transaction, self)?;
+        export_events(&transaction, self)?;
+        export_categories(&transaction, self)?;
+
+        transaction.commit()
+    }
+}
+
+// categories are stored by their lowercase names, which their `TryFrom<&str>` implementations accept
+pub fn category_name(category: impl Debug) -> String {
+    format!("{category:?}").to_ascii_lowercase()
+}
+
+fn export_attributes(connection: &Connection, reference: &WebReference) -> rusqlite::Result<()> {
+    let mut insert_attribute = connection.prepare(
+        "INSERT INTO attributes (name, tag, description, belongs_to, value_kind, value_description) VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+    )?;
+    let mut insert_belongs_to = connection.prepare(
+        "INSERT INTO attribute_belongs_to (attribute, tag, belongs) VALUES (?1, ?2, ?3)"
+    )?;
+    let mut insert_value = connection.prepare(
+        "INSERT INTO attribute_values (attribute, tag, key, name, description) VALUES (?1, ?2, ?3, ?4, ?5)"
+    )?;
+
+    for (tag, attribute) in reference.attributes.values().flat_map(|tags| tags.iter()) {
+        let belongs_to = match &attribute.belongs_to {
+            AttributeBelongsTo::Tags(_) => "tags",
+            AttributeBelongsTo::Global => "global",
+            AttributeBelongsTo::NotSupported => "not-supported",
+        };
+
+        insert_attribute.execute(params![
+            attribute.name,
+            tag,
+            attribute.description.as_ref(),
+            belongs_to,
+            attribute.values.kind(),
+            attribute.values.description().map(AsRef::<str>::as_ref),
+        ])?;
+
+        if let AttributeBelongsTo::Tags(belongs) = &attribute.belongs_to {
+            for belongs in belongs {
+                insert_belongs_to.execute(params![attribute.name, tag, belongs])?;
+            }
+        }
+
+        if let AttributeValue::Values(values) = &attribute.values {
+            for (key, value) in values {
+                insert_value.execute(params![attribute.name, tag, key, value.name, value.description.as_ref()])?;
+            }
+        }
+
+        export_supported(connection, ATTRIBUTE, &attribute.name, tag, &attribute.supported)?;
+    }
+
+    Ok(())
+}
+
+fn export_categories(connection: &Connection, reference: &WebReference) -> rusqlite::Result<()> {
+    let mut insert_category = connection.prepare(
+        "INSERT INTO categories (kind, category, name) VALUES (?1, ?2, ?3)"
+    )?;
+    let mut insert_event_category = connection.prepare(
+        "INSERT INTO event_categories (category, description) VALUES (?1, ?2)"
+    )?;
+
+    for (category, details) in &reference.tags_categorized {
+        for tag in &details.tags {
+            insert_category.execute(params![TAG, category_name(category), tag])?;
+        }
+    }
+
+    for (category, details) in &reference.attributes_categorized {
+        for attribute in &details.attributes {
+            insert_category.execute(params![ATTRIBUTE, category_name(category), attribute])?;
+        }
+    }
+
+    for (category, details) in &reference.events_categorized {
+        insert_event_category.execute(params![category_name(category), details.description.as_ref()])?;
+
+        for event in &details.events {
+            insert_category.execute(params![EVENT, category_name(category), event])?;
+        }
+    }
+
+    Ok(())
+}
+
+fn export_events(connection: &Connection, reference: &WebReference) -> rusqlite::Result<()> {
+    let mut insert_event = connection.prepare(
+        "INSERT INTO events (name, description, belongs_to, bubbles, cancelable) VALUES (?1, ?2, ?3, ?4, ?5)"
+    )?;
+    let mut insert_belongs_to = connection.prepare(
+        "INSERT INTO event_belongs_to (event, tag) VALUES (?1, ?2)"
+    )?;
+    let mut insert_object = connection.prepare(
+        "INSERT INTO event_objects (event, object) VALUES (?1, ?2)"
+    )?;
+    let mut insert_deprecated = connection.prepare(
+        "INSERT INTO event_deprecated (event, alternative) VALUES (?1, ?2)"
+    )?;
+
+    for event in reference.events.values() {
+        let belongs_to = match &event.belongs_to {
+            EventBelongsTo::NotDefined => "not-defined",
+            EventBelongsTo::AllVisible => "all-visible",
+            EventBelongsTo::Tags(_) => "tags",
+        };
+
+        insert_event.execute(params![
+            event.name,
+            event.description.as_ref(),
+            belongs_to,
+            event.details.bubbles,
+            event.details.cancelable,
+        ])?;
+
+        if let EventBelongsTo::Tags(tags) = &event.belongs_to {
+            for tag in tags {
+                insert_belongs_to.execute(params![event.name, tag])?;
+            }
+        }
+
+        for object in &event.event_objects {
+            insert_object.execute(params![event.name, object])?;
+        }
+
+        for alternative in &event.deprecated {
+            insert_deprecated.execute(params![event.name, alternative])?;
+        }
+
+        export_supported(connection, EVENT, &event.name, "", &event.supported)?;
+    }
+
+    Ok(())
+}
+
+fn export_supported(connection: &Connection, entity: &str, name: &str, tag: &str, supported: &SupportedBrowsers) -> rusqlite::Result<()> {
+    let mut insert_support = connection.prepare_cached(
+        "INSERT INTO support (entity, name, tag, browser, support, version, prefix, unprefixed, caveat) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)"
+    )?;
+    let mut insert_caveat = connection.prepare_cached(
+        "INSERT INTO caveats (entity, name, tag, caveat) VALUES (?1, ?2, ?3, ?4)"
+    )?;
+
+    for (browser, support) in &supported.supported {
+        let (kind, version, prefix, unprefixed, caveat) = match support {
+            Support::Yes => ("yes", None, None, None, None),
+            Support::No => ("no", None, None, None, None),
+            Support::Unknown => ("unknown", None, None, None, None),
+            Support::Version { version, prefix, unprefixed, caveat } => (
+                "version",
+                Some(version.to_string()),
+                prefix.map(|prefix| prefix.to_string()),
+                unprefixed.map(|unprefixed| unprefixed.to_string()),
+                Some(*caveat),
+            ),
+        };
+
+        insert_support.execute(params![entity, name, tag, browser.to_string(), kind, version, prefix, unprefixed, caveat])?;
+    }
+
+    for caveat in &supported.caveats {
+        insert_caveat.execute(params![entity, name, tag, caveat.as_ref()])?;
+    }
+
+    Ok(())
+}
+
+fn export_tags(connection: &Connection, reference: &WebReference) -> rusqlite::Result<()> {
+    let mut insert_tag = connection.prepare(
+        "INSERT INTO tags (name, description, global_attributes, global_events, alternatives, implicit_role, allowed_roles, content, kind) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)"
+    )?;
+    let mut insert_alternative = connection.prepare(
+        "INSERT INTO tag_alternatives (tag, alternative) VALUES (?1, ?2)"
+    )?;
+    let mut insert_allowed_role = connection.prepare(
+        "INSERT INTO tag_allowed_roles (tag, role) VALUES (?1, ?2)"
+    )?;
+    let mut insert_content_model = connection.prepare(
+        "INSERT INTO tag_content_model (tag, kind, name) VALUES (?1, ?2, ?3)"
+    )?;
+    let mut insert_omission = connection.prepare(
+        "INSERT INTO tag_omissions (tag, omits, description, when_empty, at_parent_end, next_element, excluded_next_comment, excluded_next_whitespace) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+    )?;
+    let mut insert_omission_tag = connection.prepare(
+        "INSERT INTO tag_omission_tags (tag, omits, kind, name) VALUES (?1, ?2, ?3, ?4)"
+    )?;
+    let mut insert_attribute = connection.prepare(
+        "INSERT INTO tag_attributes (tag, attribute, optional) VALUES (?1, ?2, ?3)"
+    )?;
+
+    for tag in reference.tags.values() {
+        let alternatives = match &tag.alternatives {
+            TagAlternatives::None => "none",
+            TagAlternatives::Css(_) => "css",
+            TagAlternatives::Tags(_) => "tags",
+        };
+
+        let allowed_roles = match &tag.aria.allowed_roles {
+            AllowedAriaRoles::Any => "any",
+            AllowedAriaRoles::None => "none",
+            AllowedAriaRoles::Roles(_) => "roles",
+        };
+
+        let content = tag.content_model.as_ref().map(|model| category_name(model.content));
+
+        insert_tag.execute(params![tag.name, tag.description.as_ref(), tag.global_attributes, tag.global_events, alternatives, tag.aria.implicit_role, allowed_roles, content, category_name(tag.syntax.kind)])?;
+
+        if let TagAlternatives::Css(alternatives) | TagAlternatives::Tags(alternatives) = &tag.alternatives {
+            for alternative in alternatives {
+                insert_alternative.execute(params![tag.name, alternative])?;
+            }
+        }
+
+        if let AllowedAriaRoles::Roles(roles) = &tag.aria.allowed_roles {
+            for role in roles {
+                insert_allowed_role.execute(params![tag.name, role])?;
+            }
+        }
+
+        if let Some(model) = &tag.content_model {
+            let categories = [
+                (CONTENT_CATEGORY, &model.categories),
+                (CONTENT_CONTENT_CATEGORY, &model.content_categories),
+                (CONTENT_EXCLUDED_CATEGORY, &model.excluded_categories),
+            ];
+
+            for (kind, categories) in categories {
+                for category in categories {
+                    insert_content_model.execute(params![tag.name, kind, category_name(category)])?;
+                }
+            }
+
+            let tags = [
+                (CONTENT_CONTENT_TAG, &model.content_tags),
+                (CONTENT_EXCLUDED_TAG, &model.excluded_tags),
+                (CONTENT_PARENT, &model.parents),
+            ];
+
+            for (kind, tags) in tags {
+                for name in tags {
+                    insert_content_model.execute(params![tag.name, kind, name])?;
+                }
+            }
+        }
+
+        let omissions = [
+            (OMITS_START, &tag.syntax.start_tag_omission),
+            (OMITS_END, &tag.syntax.end_tag_omission),
+        ];
+
+        for (omits, rule) in omissions {
+            let Some(rule) = rule else {
+                continue;
+            };
+
+            insert_omission.execute(params![
+                tag.name, omits, rule.description.as_ref(), rule.when_empty, rule.at_parent_end, rule.next_element,
+                rule.excluded_next_comment, rule.excluded_next_whitespace,
+            ])?;
+
+            let tags = [
+                (OMISSION_EXCLUDED_PARENT, &rule.excluded_parents),
+                (OMISSION_NEXT, &rule.next_tags),
+                (OMISSION_EXCLUDED_NEXT, &rule.excluded_next_tags),
+            ];
+
+            for (kind, tags) in tags {
+                for name in tags {
+                    insert_omission_tag.execute(params![tag.name, omits, kind, name])?;
+                }
+            }
+        }
+
+        for attribute in &tag.attributes {
+            insert_attribute.execute(params![tag.name, attribute, false])?;
+        }
+
+        for attribute in &tag.optional_attributes {
+            insert_attribute.execute(params![tag.name, attribute, true])?;
+        }
+
+        export_supported(connection, TAG, &tag.name, "", &tag.supported)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,410 @@
+use std::borrow::Cow;
+use std::path::Path;
+use std::str::FromStr;
+
+use rusqlite::{Connection, OpenFlags, OptionalExtension, Params, params};
+use rusqlite::types::Type;
+
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+use crate::store::sqlite_export::{
+    ATTRIBUTE, category_name, CONTENT_CATEGORY, CONTENT_CONTENT_CATEGORY, CONTENT_CONTENT_TAG, CONTENT_EXCLUDED_CATEGORY,
+    CONTENT_EXCLUDED_TAG, CONTENT_PARENT, EVENT, OMISSION_EXCLUDED_NEXT, OMISSION_EXCLUDED_PARENT, OMISSION_NEXT, OMITS_END,
+    OMITS_START, TAG,
+};
+
+/// a [`ReferenceStore`] that reads entities from an exported reference on demand, see [`WebReference::export_sqlite`]
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Connection,
+}
+
+impl SqliteStore {
+    /// exports a reference into a new database file and opens a store of it
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the database can not be created or the reference fails to export
+    pub fn create<P: AsRef<Path>>(path: P, reference: &WebReference) -> rusqlite::Result<Self> {
+        let mut connection = Connection::open(path)?;
+
+        reference.export_sqlite(&mut connection)?;
+
+        Ok(Self { connection })
+    }
+
+    ///
+    #[must_use]
+    pub const fn from_connection(connection: Connection) -> Self {
+        Self { connection }
+    }
+
+    /// exports a reference into an in memory database, mostly useful for testing
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the reference fails to export
+    pub fn in_memory(reference: &WebReference) -> rusqlite::Result<Self> {
+        let mut connection = Connection::open_in_memory()?;
+
+        reference.export_sqlite(&mut connection)?;
+
+        Ok(Self { connection })
+    }
+
+    /// opens an exported reference read only
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the database can not be opened
+    pub fn open<P: AsRef<Path>>(path: P) -> rusqlite::Result<Self> {
+        Ok(Self { connection: Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)? })
+    }
+
+    ///
+    #[must_use]
+    pub const fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    fn exists(&self, sql: &str, params: impl Params) -> rusqlite::Result<bool> {
+        self.connection.prepare_cached(sql)?.query_row(params, |row| row.get(0))
+    }
+
+    fn load_attribute(&self, attribute: &str, tag: &str) -> rusqlite::Result<Option<Attribute>> {
+        let row = self.connection
+            .prepare_cached("SELECT name, description, belongs_to, value_kind, value_description FROM attributes WHERE name = ?1 AND tag = ?2")?
+            .query_row(params![attribute, tag], |row| Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+            )))
+            .optional()?;
+
+        let Some((name, description, belongs_to, value_kind, value_description)) = row else {
+            return Ok(None);
+        };
+
+        let belongs_to = match belongs_to.as_str() {
+            "tags" => AttributeBelongsTo::Tags(
+                self.strings("SELECT belongs FROM attribute_belongs_to WHERE attribute = ?1 AND tag = ?2 ORDER BY rowid", params![name, tag])?
+                    .into_iter().collect()
+            ),
+            "global" => AttributeBelongsTo::Global,
+            _ => AttributeBelongsTo::NotSupported,
+        };
+
+        let values = if value_kind == "Values" {
+            let mut statement = self.connection
+                .prepare_cached("SELECT key, name, description FROM attribute_values WHERE attribute = ?1 AND tag = ?2 ORDER BY rowid")?;
+
+            let values = statement.query_map(params![name, tag], |row| {
+                let name = row.get::<_, String>(1)?;
+                let description = Description::verbatim(row.get::<_, String>(2)?);
+
+                Ok((row.get::<_, String>(0)?, Value { name, description }))
+            })?;
+
+            AttributeValue::Values(values.collect::<rusqlite::Result<_>>()?)
+        } else {
+            AttributeValue::from_kind(&value_kind, Description::verbatim(value_description.unwrap_or_default()))
+                .map_err(|err| conversion_error(3, err))?
+        };
+
+        let supported = self.load_supported(ATTRIBUTE, &name, tag)?;
+
+        Ok(Some(Attribute { name, belongs_to, description: Description::verbatim(description), supported, values }))
+    }
+
+    fn load_event(&self, event: &str) -> rusqlite::Result<Option<Event>> {
+        let row = self.connection
+            .prepare_cached("SELECT name, description, belongs_to, bubbles, cancelable FROM events WHERE name = ?1")?
+            .query_row(params![event], |row| Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, bool>(3)?,
+                row.get::<_, bool>(4)?,
+            )))
+            .optional()?;
+
+        let Some((name, description, belongs_to, bubbles, cancelable)) = row else {
+            return Ok(None);
+        };
+
+        let belongs_to = match belongs_to.as_str() {
+            "tags" => EventBelongsTo::Tags(
+                self.strings("SELECT tag FROM event_belongs_to WHERE event = ?1 ORDER BY rowid", params![name])?
+                    .into_iter().collect()
+            ),
+            "all-visible" => EventBelongsTo::AllVisible,
+            _ => EventBelongsTo::NotDefined,
+        };
+
+        let event_objects = self.strings("SELECT object FROM event_objects WHERE event = ?1 ORDER BY rowid", params![name])?
+            .into_iter().collect();
+
+        let deprecated = self.strings("SELECT alternative FROM event_deprecated WHERE event = ?1 ORDER BY rowid", params![name])?
+            .into_iter().collect();
+
+        let supported = self.load_supported(EVENT, &name, "")?;
+
+        Ok(Some(Event {
+            name,
+            belongs_to,
+            description: Description::verbatim(description),
+            details: EventDetails { bubbles, cancelable },
+            event_objects,
+            supported,
+            deprecated,
+        }))
+    }
+
+    fn load_supported(&self, entity: &str, name: &str, tag: &str) -> rusqlite::Result<SupportedBrowsers> {
+        let mut statement = self.connection.prepare_cached(
+            "SELECT browser, support, version, prefix, unprefixed, caveat FROM support WHERE entity = ?1 AND name = ?2 AND tag = ?3 ORDER BY rowid"
+        )?;
+
+        let supported = statement.query_map(params![entity, name, tag], |row| {
+            let browser = Browser::from_str(&row.get::<_, String>(0)?)
+                .map_err(|err| conversion_error(0, err))?;
+
+            let support = match row.get::<_, String>(1)?.as_str() {
+                "yes" => Support::Yes,
+                "no" => Support::No,
+                "version" => Support::Version {
+                    version: parse_column(row.get(2)?, 2)?.unwrap_or_default(),
+                    prefix: parse_column(row.get(3)?, 3)?,
+                    unprefixed: parse_column(row.get(4)?, 4)?,
+                    caveat: row.get::<_, Option<usize>>(5)?.unwrap_or_default(),
+                },
+                _ => Support::Unknown,
+            };
+
+            Ok((browser, support))
+        })?;
+
+        let supported = supported.collect::<rusqlite::Result<_>>()?;
+
+        let caveats = self.strings("SELECT caveat FROM caveats WHERE entity = ?1 AND name = ?2 AND tag = ?3 ORDER BY rowid", params![entity, name, tag])?
+            .into_iter().map(Description::verbatim).collect();
+
+        Ok(SupportedBrowsers { supported, caveats })
+    }
+
+    fn load_tag(&self, tag: &str) -> rusqlite::Result<Option<Tag>> {
+        let row = self.connection
+            .prepare_cached("SELECT name, description, global_attributes, global_events, alternatives, implicit_role, allowed_roles, content, kind FROM tags WHERE name = ?1")?
+            .query_row(params![tag], |row| Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, bool>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, String>(6)?,
+                row.get::<_, Option<String>>(7)?,
+                row.get::<_, String>(8)?,
+            )))
+            .optional()?;
+
+        let Some((name, description, global_attributes, global_events, alternatives, implicit_role, allowed_roles, content, kind)) = row else {
+            return Ok(None);
+        };
+
+        let alternatives = match alternatives.as_str() {
+            kind @ ("css" | "tags") => {
+                let alternatives = self.strings("SELECT alternative FROM tag_alternatives WHERE tag = ?1 ORDER BY rowid", params![name])?
+                    .into_iter().collect();
+
+                if kind == "css" { TagAlternatives::Css(alternatives) } else { TagAlternatives::Tags(alternatives) }
+            }
+            _ => TagAlternatives::None,
+        };
+
+        let allowed_roles = match allowed_roles.as_str() {
+            "none" => AllowedAriaRoles::None,
+            "roles" => AllowedAriaRoles::Roles(
+                self.strings("SELECT role FROM tag_allowed_roles WHERE tag = ?1 ORDER BY rowid", params![name])?
+                    .into_iter().collect()
+            ),
+            _ => AllowedAriaRoles::Any,
+        };
+
+        let content_model = content.map(|content| self.load_content_model(&name, &content)).transpose()?;
+
+        let syntax = TagSyntax {
+            kind: ElementKind::try_from(kind.as_str()).map_err(|err| conversion_error(8, err))?,
+            start_tag_omission: self.load_omission(&name, OMITS_START)?,
+            end_tag_omission: self.load_omission(&name, OMITS_END)?,
+        };
+
+        let attributes = self.strings("SELECT attribute FROM tag_attributes WHERE tag = ?1 AND NOT optional ORDER BY rowid", params![name])?
+            .into_iter().collect();
+
+        let optional_attributes = self.strings("SELECT attribute FROM tag_attributes WHERE tag = ?1 AND optional ORDER BY rowid", params![name])?
+            .into_iter().collect();
+
+        let supported = self.load_supported(TAG, &name, "")?;
+
+        Ok(Some(Tag {
+            name,
+            description: Description::verbatim(description),
+            supported,
+            attributes,
+            optional_attributes,
+            global_attributes,
+            global_events,
+            alternatives,
+            aria: TagAria { implicit_role, allowed_roles },
+            content_model,
+            syntax,
+        }))
+    }
+
+    fn load_omission(&self, tag: &str, omits: &str) -> rusqlite::Result<Option<OmissionRule>> {
+        let row = self.connection
+            .prepare_cached("SELECT description, when_empty, at_parent_end, next_element, excluded_next_comment, excluded_next_whitespace FROM tag_omissions WHERE tag = ?1 AND omits = ?2")?
+            .query_row(params![tag, omits], |row| Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, bool>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, bool>(3)?,
+                row.get::<_, bool>(4)?,
+                row.get::<_, bool>(5)?,
+            )))
+            .optional()?;
+
+        let Some((description, when_empty, at_parent_end, next_element, excluded_next_comment, excluded_next_whitespace)) = row else {
+            return Ok(None);
+        };
+
+        let names = |kind: &str| self.strings(
+            "SELECT name FROM tag_omission_tags WHERE tag = ?1 AND omits = ?2 AND kind = ?3 ORDER BY rowid",
+            params![tag, omits, kind],
+        );
+
+        Ok(Some(OmissionRule {
+            description: Description::verbatim(description),
+            when_empty,
+            at_parent_end,
+            excluded_parents: names(OMISSION_EXCLUDED_PARENT)?.into_iter().collect(),
+            next_tags: names(OMISSION_NEXT)?.into_iter().collect(),
+            next_element,
+            excluded_next_tags: names(OMISSION_EXCLUDED_NEXT)?.into_iter().collect(),
+            excluded_next_comment,
+            excluded_next_whitespace,
+        }))
+    }
+
+    fn load_content_model(&self, tag: &str, content: &str) -> rusqlite::Result<ContentModel> {
+        let content = match content {
+            "any" => PermittedContent::Any,
+            "nothing" => PermittedContent::Nothing,
+            "text" => PermittedContent::Text,
+            "transparent" => PermittedContent::Transparent,
+            "elements" => PermittedContent::Elements,
+            unexpected => return Err(conversion_error(7, format!("{unexpected:?} is not a valid permitted content"))),
+        };
+
+        let names = |kind: &str| self.strings("SELECT name FROM tag_content_model WHERE tag = ?1 AND kind = ?2 ORDER BY rowid", params![tag, kind]);
+
+        let categories = |kind: &str| names(kind)?.iter()
+            .map(|name| ContentCategory::try_from(name.as_str()).map_err(|err| conversion_error(0, err)))
+            .collect::<rusqlite::Result<HasContentCategories>>();
+
+        Ok(ContentModel {
+            categories: categories(CONTENT_CATEGORY)?,
+            content,
+            content_categories: categories(CONTENT_CONTENT_CATEGORY)?,
+            content_tags: names(CONTENT_CONTENT_TAG)?.into_iter().collect(),
+            excluded_categories: categories(CONTENT_EXCLUDED_CATEGORY)?,
+            excluded_tags: names(CONTENT_EXCLUDED_TAG)?.into_iter().collect(),
+            parents: names(CONTENT_PARENT)?.into_iter().collect(),
+        })
+    }
+
+    fn strings(&self, sql: &str, params: impl Params) -> rusqlite::Result<Vec<String>> {
+        let mut statement = self.connection.prepare_cached(sql)?;
+        let rows = statement.query_map(params, |row| row.get(0))?;
+
+        rows.collect()
+    }
+}
+
+impl ReferenceStore for SqliteStore {
+    type Error = rusqlite::Error;
+
+    fn is_valid_attribute(&self, attribute: &str) -> Result<bool, Self::Error> {
+        self.exists("SELECT EXISTS (SELECT 1 FROM attributes WHERE name = ?1)", params![attribute])
+    }
+
+    fn is_valid_event(&self, event: &str) -> Result<bool, Self::Error> {
+        let sql = "SELECT EXISTS (SELECT 1 FROM events WHERE name = ?1)";
+
+        if event.starts_with(EVENT_PREFIX) {
+            self.exists(sql, params![event])
+        } else {
+            self.exists(sql, params![format!("{EVENT_PREFIX}{event}")])
+        }
+    }
+
+    fn is_valid_tag(&self, tag: &str) -> Result<bool, Self::Error> {
+        self.exists("SELECT EXISTS (SELECT 1 FROM tags WHERE name = ?1)", params![tag])
+    }
+
+    fn get_attribute(&self, attribute: &str, tag: &str) -> Result<Option<Cow<'_, Attribute>>, Self::Error> {
+        Ok(self.load_attribute(attribute, tag)?.map(Cow::Owned))
+    }
+
+    fn get_event(&self, event: &str) -> Result<Option<Cow<'_, Event>>, Self::Error> {
+        Ok(self.load_event(event)?.map(Cow::Owned))
+    }
+
+    fn get_events_of_category(&self, category: EventCategory) -> Result<Vec<Cow<'_, Event>>, Self::Error> {
+        let events = self.strings(
+            "SELECT name FROM categories WHERE kind = ?1 AND category = ?2 ORDER BY rowid",
+            params![EVENT, category_name(category)],
+        )?;
+
+        events.iter()
+            .filter_map(|event| self.load_event(event).transpose())
+            .map(|event| event.map(Cow::Owned))
+            .collect()
+    }
+
+    fn get_tag(&self, tag: &str) -> Result<Option<Cow<'_, Tag>>, Self::Error> {
+        Ok(self.load_tag(tag)?.map(Cow::Owned))
+    }
+
+    fn get_tag_attributes(&self, tag: &str) -> Result<Vec<Cow<'_, Attribute>>, Self::Error> {
+        let attributes = self.strings("SELECT attribute FROM tag_attributes WHERE tag = ?1 ORDER BY rowid", params![tag])?;
+
+        attributes.iter()
+            .filter_map(|attribute| self.load_attribute(attribute, tag).transpose())
+            .map(|attribute| attribute.map(Cow::Owned))
+            .collect()
+    }
+
+    fn get_tags_of_category(&self, category: TagCategory) -> Result<Vec<Cow<'_, Tag>>, Self::Error> {
+        let tags = self.strings(
+            "SELECT name FROM categories WHERE kind = ?1 AND category = ?2 ORDER BY rowid",
+            params![TAG, category_name(category)],
+        )?;
+
+        tags.iter()
+            .filter_map(|tag| self.load_tag(tag).transpose())
+            .map(|tag| tag.map(Cow::Owned))
+            .collect()
+    }
+}
+
+fn conversion_error(column: usize, err: String) -> rusqlite::Error {
+    rusqlite::Error::FromSqlConversionFailure(column, Type::Text, err.into())
+}
+
+fn parse_column<T: FromStr<Err=String>>(value: Option<String>, column: usize) -> rusqlite::Result<Option<T>> {
+    value.map(|value| T::from_str(&value).map_err(|err| conversion_error(column, err))).transpose()
+}
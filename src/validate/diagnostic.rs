@@ -0,0 +1,168 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::prelude::*;
+
+///
+pub type Diagnostics = Vec<Diagnostic>;
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Diagnostic {
+    ///
+    pub rule: Rule,
+
+    ///
+    pub severity: Severity,
+
+    ///
+    pub span: Span,
+
+    ///
+    pub message: String,
+}
+
+impl Diagnostic {
+    ///
+    #[must_use]
+    pub fn new(rule: Rule, span: Span, message: impl Into<String>) -> Self {
+        Self {
+            rule,
+            severity: rule.severity(),
+            span,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let Position { line, column, .. } = self.span.start;
+
+        write!(fmt, "{line}:{column}: {}[{}]: {}", self.severity, self.rule, self.message)
+    }
+}
+
+/// a diagnostic of a start tag, without a span, i.e. of a `TagValidator`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TagDiagnostic {
+    ///
+    pub rule: Rule,
+
+    ///
+    pub target: DiagnosticTarget,
+
+    ///
+    pub message: String,
+}
+
+/// the part of a start tag a diagnostic is about, attributes by their index
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DiagnosticTarget {
+    ///
+    Tag,
+
+    ///
+    AttributeName(usize),
+
+    /// the value of an attribute, or its name if it has none
+    AttributeValue(usize),
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Severity {
+    ///
+    Error,
+
+    ///
+    Warning,
+
+    ///
+    Information,
+}
+
+impl Display for Severity {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Information => "info",
+        })
+    }
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Rule {
+    ///
+    UnknownTag,
+
+    ///
+    ObsoleteTag,
+
+    ///
+    UnknownAttribute,
+
+    ///
+    DisallowedAttribute,
+
+    /// an obsolete presentational attribute, i.e. `bgcolor`, that css replaces
+    PresentationalAttribute,
+
+    /// a `role` the tag does not allow, i.e. `button` on `<ul>`
+    DisallowedRole,
+
+    /// an aria state or property the tag's role does not support, i.e. `aria-checked` on a `link`
+    UnsupportedAriaAttribute,
+
+    ///
+    UnknownEvent,
+
+    ///
+    InvalidAttributeValue,
+}
+
+impl Rule {
+    /// stable identifier of a rule, safe to use in configuration and suppressions
+    #[must_use]
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::UnknownTag => "unknown-tag",
+            Self::ObsoleteTag => "obsolete-tag",
+            Self::UnknownAttribute => "unknown-attribute",
+            Self::DisallowedAttribute => "disallowed-attribute",
+            Self::PresentationalAttribute => "presentational-attribute",
+            Self::DisallowedRole => "disallowed-role",
+            Self::UnsupportedAriaAttribute => "unsupported-aria-attribute",
+            Self::UnknownEvent => "unknown-event",
+            Self::InvalidAttributeValue => "invalid-attribute-value",
+        }
+    }
+
+    ///
+    #[must_use]
+    pub const fn severity(self) -> Severity {
+        match self {
+            Self::UnknownTag | Self::UnknownAttribute | Self::UnknownEvent |
+            Self::InvalidAttributeValue => Severity::Error,
+            Self::ObsoleteTag | Self::DisallowedAttribute | Self::PresentationalAttribute |
+            Self::DisallowedRole | Self::UnsupportedAriaAttribute => Severity::Warning,
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.id())
+    }
+}
@@ -0,0 +1,6 @@
+//!
+
+pub mod diagnostic;
+pub mod span;
+pub mod tokenizer;
+pub mod validate_impl;
@@ -0,0 +1,69 @@
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Position {
+    ///
+    pub line: usize,
+
+    ///
+    pub column: usize,
+
+    ///
+    pub offset: usize,
+}
+
+///
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(any(feature = "serialize", feature = "deserialize"), serde(rename_all = "kebab-case"))]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Span {
+    ///
+    pub start: Position,
+
+    ///
+    pub end: Position,
+}
+
+///
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    ///
+    #[must_use]
+    pub fn new(source: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self { line_starts }
+    }
+
+    /// line and column are one based, column counts characters not bytes
+    #[must_use]
+    pub fn position(&self, source: &str, offset: usize) -> Position {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+
+        let line_start = self.line_starts[line];
+        let column = source.get(line_start..offset).map_or(0, |text| text.chars().count());
+
+        Position { line: line + 1, column: column + 1, offset }
+    }
+
+    ///
+    #[must_use]
+    pub fn span(&self, source: &str, start: usize, end: usize) -> Span {
+        Span {
+            start: self.position(source, start),
+            end: self.position(source, end),
+        }
+    }
+}
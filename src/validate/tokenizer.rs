@@ -0,0 +1,208 @@
+use std::ops::Range;
+
+const RAW_TEXT_TAGS: [&str; 8] = [
+    "script", "style", "textarea", "title", "xmp", "noembed", "noframes", "plaintext"
+];
+
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TokenAttribute<'a> {
+    ///
+    pub name: &'a str,
+
+    ///
+    pub name_span: Range<usize>,
+
+    ///
+    pub value: Option<&'a str>,
+
+    ///
+    pub value_span: Option<Range<usize>>,
+}
+
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Token<'a> {
+    ///
+    StartTag {
+        ///
+        name: &'a str,
+
+        ///
+        span: Range<usize>,
+
+        ///
+        attributes: Vec<TokenAttribute<'a>>,
+
+        ///
+        self_closing: bool,
+    },
+
+    ///
+    EndTag {
+        ///
+        name: &'a str,
+
+        ///
+        span: Range<usize>,
+    },
+}
+
+///
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+    source: &'a str,
+    position: usize,
+    raw_text: Option<&'a str>,
+}
+
+impl<'a> Tokenizer<'a> {
+    ///
+    #[must_use]
+    pub const fn new(source: &'a str) -> Self {
+        Self { source, position: 0, raw_text: None }
+    }
+
+    const fn bytes(&self) -> &'a [u8] {
+        self.source.as_bytes()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes().get(self.position).copied()
+    }
+
+    fn skip_past(&mut self, pattern: &str) {
+        self.position = self.source[self.position..].find(pattern)
+            .map_or(self.source.len(), |idx| self.position + idx + pattern.len());
+    }
+
+    fn skip_raw_text(&mut self, tag: &str) {
+        let closing = format!("</{tag}");
+        let remaining = self.source[self.position..].to_ascii_lowercase();
+
+        self.position = remaining.find(&closing)
+            .map_or(self.source.len(), |idx| self.position + idx);
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|chr| chr.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> Range<usize> {
+        let start = self.position;
+
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+
+        start..self.position
+    }
+
+    fn read_attribute(&mut self) -> TokenAttribute<'a> {
+        let name_span = self.take_while(|chr| !chr.is_ascii_whitespace() && chr != b'=' && chr != b'>' && chr != b'/');
+
+        self.skip_whitespace();
+
+        let value_span = if self.peek() == Some(b'=') {
+            self.position += 1;
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(quote @ (b'"' | b'\'')) => {
+                    self.position += 1;
+
+                    let value = self.take_while(|chr| chr != quote);
+
+                    if self.peek().is_some() { self.position += 1; }
+
+                    Some(value)
+                }
+                _ => Some(self.take_while(|chr| !chr.is_ascii_whitespace() && chr != b'>'))
+            }
+        } else {
+            None
+        };
+
+        TokenAttribute {
+            name: &self.source[name_span.clone()],
+            name_span,
+            value: value_span.clone().map(|span| &self.source[span]),
+            value_span,
+        }
+    }
+
+    fn read_end_tag(&mut self, start: usize) -> Token<'a> {
+        self.position += 2;
+
+        let name = self.take_while(|chr| !chr.is_ascii_whitespace() && chr != b'>');
+
+        self.skip_past(">");
+
+        Token::EndTag { name: &self.source[name], span: start..self.position }
+    }
+
+    fn read_start_tag(&mut self, start: usize) -> Token<'a> {
+        self.position += 1;
+
+        let name = self.take_while(|chr| !chr.is_ascii_whitespace() && chr != b'>' && chr != b'/');
+        let mut attributes = vec![];
+        let mut self_closing = false;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                None => break,
+                Some(b'>') => {
+                    self.position += 1;
+
+                    break;
+                }
+                Some(b'/') => {
+                    self.position += 1;
+                    self_closing = self.peek() == Some(b'>');
+                }
+                Some(_) => attributes.push(self.read_attribute())
+            }
+        }
+
+        let name = &self.source[name];
+
+        if !self_closing {
+            self.raw_text = RAW_TEXT_TAGS.iter().copied().find(|tag| name.eq_ignore_ascii_case(tag));
+        }
+
+        Token::StartTag { name, span: start..self.position, attributes, self_closing }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tag) = self.raw_text.take() {
+            self.skip_raw_text(tag);
+        }
+
+        loop {
+            self.position += self.source.get(self.position..)?.find('<')?;
+
+            let start = self.position;
+            let remaining = &self.source[start..];
+
+            if remaining.starts_with("<!--") {
+                self.skip_past("-->");
+            } else if remaining.starts_with("<!") || remaining.starts_with("<?") {
+                self.skip_past(">");
+            } else if remaining.starts_with("</") {
+                return Some(self.read_end_tag(start));
+            } else if remaining.as_bytes().get(1).is_some_and(u8::is_ascii_alphabetic) {
+                return Some(self.read_start_tag(start));
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+}
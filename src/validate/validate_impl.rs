@@ -0,0 +1,276 @@
+use crate::models::web_impl::EVENT_PREFIX;
+use crate::prelude::*;
+use crate::validate::span::LineIndex;
+use crate::validate::tokenizer::{Token, Tokenizer};
+
+impl WebReference {
+    ///
+    #[must_use]
+    pub fn validate_document(&self, html: &str) -> Diagnostics {
+        let lines = LineIndex::new(html);
+        let mut validator = TagValidator::new(self);
+        let mut diagnostics = Diagnostics::new();
+
+        for token in Tokenizer::new(html) {
+            match token {
+                Token::StartTag { name, span, attributes, self_closing } => {
+                    let values = attributes.iter()
+                        .map(|attribute| (attribute.name, attribute.value))
+                        .collect::<Vec<_>>();
+
+                    for TagDiagnostic { rule, target, message } in validator.start_tag(name, &values, self_closing) {
+                        let span = match target {
+                            DiagnosticTarget::Tag => span.clone(),
+                            DiagnosticTarget::AttributeName(idx) => attributes[idx].name_span.clone(),
+                            DiagnosticTarget::AttributeValue(idx) => attributes[idx].value_span.clone()
+                                .unwrap_or_else(|| attributes[idx].name_span.clone()),
+                        };
+
+                        diagnostics.push(Diagnostic::new(rule, lines.span(html, span.start, span.end), message));
+                    }
+                }
+                Token::EndTag { name, .. } =>
+                    validator.end_tag(name),
+            }
+        }
+
+        diagnostics
+    }
+
+    ///
+    #[must_use]
+    pub fn is_tag_attribute(&self, attribute: &str, tag: &Tag) -> bool {
+        tag.attributes.contains(attribute) ||
+            tag.optional_attributes.contains(attribute) ||
+            self.find_tag_attribute(attribute, tag).is_some()
+    }
+}
+
+// the svg root and `foreignObject` switch the namespace of their content
+const FOREIGN_OBJECT: &str = "foreignObject";
+
+const ARIA_PREFIX: &str = "aria-";
+const ROLE: &str = "role";
+
+/// validates start tags in document order, tracking the namespace the svg root and `foreignObject` switch
+/// their content to, for markup that is not an html string, i.e. the token tree of a macro
+#[derive(Debug, Clone)]
+pub struct TagValidator<'a> {
+    reference: &'a WebReference,
+    diagnostics: Vec<TagDiagnostic>,
+    // the namespace of the content of open elements that switched it, with their name and
+    // the depth of same named elements nested in them
+    namespaces: Vec<(Namespace, &'static str, usize)>,
+}
+
+impl<'a> TagValidator<'a> {
+    ///
+    #[must_use]
+    pub const fn new(reference: &'a WebReference) -> Self {
+        Self { reference, diagnostics: Vec::new(), namespaces: Vec::new() }
+    }
+
+    /// diagnostics of a start tag, with the names and values of its attributes, entering the namespace of its content
+    pub fn start_tag(&mut self, name: &str, attributes: &[(&str, Option<&str>)], self_closing: bool) -> Vec<TagDiagnostic> {
+        let namespace = self.enter(name, self_closing);
+
+        self.validate_tag(namespace, name, attributes);
+
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    /// leaves the namespace the element of an end tag switched its content to, if any
+    pub fn end_tag(&mut self, name: &str) {
+        self.leave(name);
+    }
+}
+
+impl TagValidator<'_> {
+    fn namespace(&self) -> Namespace {
+        self.namespaces.last().map_or(Namespace::Html, |(namespace, _, _)| *namespace)
+    }
+
+    // the namespace of a start tag, switching the namespace of its content if it is a root or `foreignObject`
+    fn enter(&mut self, name: &str, self_closing: bool) -> Namespace {
+        let current = self.namespace();
+
+        let switch = match current {
+            Namespace::Html => Namespace::Svg.root_tag()
+                .filter(|root| name.eq_ignore_ascii_case(root))
+                .map(|root| (Namespace::Svg, root)),
+            Namespace::Svg => name.eq_ignore_ascii_case(FOREIGN_OBJECT)
+                .then_some((Namespace::Html, FOREIGN_OBJECT)),
+        };
+
+        let namespace = match switch {
+            Some((Namespace::Svg, _)) => Namespace::Svg,
+            _ => current,
+        };
+
+        if self_closing {
+            return namespace;
+        }
+
+        match (switch, self.namespaces.last_mut()) {
+            (Some((content, element)), _) => self.namespaces.push((content, element, 0)),
+            (None, Some((_, element, depth))) if name.eq_ignore_ascii_case(element) => *depth += 1,
+            _ => {}
+        }
+
+        namespace
+    }
+
+    fn leave(&mut self, name: &str) {
+        if let Some((_, element, depth)) = self.namespaces.last_mut() {
+            if name.eq_ignore_ascii_case(element) {
+                if *depth == 0 {
+                    self.namespaces.pop();
+                } else {
+                    *depth -= 1;
+                }
+            }
+        }
+    }
+
+    // a suggestion of the css properties that replace a presentational attribute, if any
+    fn css_suggestion(&self, attribute: &str) -> Option<String> {
+        let properties = self.reference.get_css_alternatives(attribute).into_iter()
+            .map(|property| property.name.clone())
+            .collect::<AlternativeCssProperties>();
+
+        (!properties.is_empty()).then(|| css_suggestion(&properties))
+    }
+
+    fn report(&mut self, rule: Rule, target: DiagnosticTarget, message: String) {
+        self.diagnostics.push(TagDiagnostic { rule, target, message });
+    }
+
+    fn validate_attribute(&mut self, namespace: Namespace, tag: &Tag, idx: usize, attribute: (&str, Option<&str>)) {
+        if namespace == Namespace::Svg {
+            return self.validate_svg_attribute(tag, idx, attribute);
+        }
+
+        let reference = self.reference;
+        let name = attribute.0.to_ascii_lowercase();
+        let target = DiagnosticTarget::AttributeName(idx);
+
+        if name.starts_with(EVENT_PREFIX) && !reference.is_valid_attribute(&name) {
+            if !reference.is_valid_event(&name) {
+                self.report(Rule::UnknownEvent, target, format!("unknown event handler attribute {name:?}{}", did_you_mean(reference.suggest_event(&name).into_iter().map(|event| format!("{event:?}")))));
+            }
+        } else if reference.is_tag_attribute(&name, tag) {
+            self.validate_attribute_value(namespace, tag, &name, idx, attribute.1);
+        } else if reference.is_valid_attribute(&name) {
+            self.report(Rule::DisallowedAttribute, target, format!("attribute {name:?} is not allowed on <{}>", tag.name));
+        } else if let Some(suggestion) = self.css_suggestion(&name) {
+            self.report(Rule::PresentationalAttribute, target, format!("attribute {name:?} is obsolete, {suggestion}"));
+        } else {
+            self.report(Rule::UnknownAttribute, target, format!("unknown attribute {name:?} on <{}>{}", tag.name, did_you_mean(reference.suggest_attribute(&tag.name, &name).into_iter().map(|attribute| format!("{attribute:?}")))));
+        }
+    }
+
+    fn validate_attribute_value(&mut self, namespace: Namespace, tag: &Tag, name: &str, idx: usize, value: Option<&str>) {
+        let Some(details) = self.reference.find_namespaced_tag_attribute(namespace, name, tag) else {
+            return;
+        };
+
+        if let Err(err) = self.reference.validate_value(name, details, value.unwrap_or_default()) {
+            self.report(Rule::InvalidAttributeValue, DiagnosticTarget::AttributeValue(idx), err.to_string());
+        }
+    }
+
+    // svg attributes are case sensitive, i.e. `viewBox`, and `xmlns` or prefixed attributes, i.e. `xlink:href`,
+    // belong to other namespaces
+    fn validate_svg_attribute(&mut self, tag: &Tag, idx: usize, (name, value): (&str, Option<&str>)) {
+        const XMLNS: &str = "xmlns";
+
+        let reference = self.reference;
+        let target = DiagnosticTarget::AttributeName(idx);
+
+        if name == XMLNS || name.contains(':') {
+            return;
+        }
+
+        if name.to_ascii_lowercase().starts_with(EVENT_PREFIX) && !reference.is_valid_namespaced_attribute(Namespace::Svg, name) {
+            if !reference.is_valid_event(&name.to_ascii_lowercase()) {
+                self.report(Rule::UnknownEvent, target, format!("unknown event handler attribute {name:?}"));
+            }
+        } else if reference.find_namespaced_tag_attribute(Namespace::Svg, name, tag).is_some() {
+            self.validate_attribute_value(Namespace::Svg, tag, name, idx, value);
+        } else if reference.is_valid_namespaced_attribute(Namespace::Svg, name) {
+            self.report(Rule::DisallowedAttribute, target, format!("attribute {name:?} is not allowed on svg <{}>", tag.name));
+        } else {
+            self.report(Rule::UnknownAttribute, target, format!("unknown attribute {name:?} on svg <{}>", tag.name));
+        }
+    }
+
+    fn validate_tag(&mut self, namespace: Namespace, name: &str, attributes: &[(&str, Option<&str>)]) {
+        if namespace == Namespace::Svg {
+            let Some(tag) = self.reference.get_namespaced_tag(namespace, name) else {
+                self.report(Rule::UnknownTag, DiagnosticTarget::Tag, format!("unknown svg tag <{name}>"));
+
+                return;
+            };
+
+            for (idx, attribute) in attributes.iter().enumerate() {
+                self.validate_attribute(namespace, tag, idx, *attribute);
+            }
+
+            return;
+        }
+
+        let reference = self.reference;
+        let name = name.to_ascii_lowercase();
+
+        // custom elements are not part of the reference
+        if name.contains('-') {
+            return;
+        }
+
+        let Some(tag) = reference.get_tag(&name) else {
+            self.report(Rule::UnknownTag, DiagnosticTarget::Tag, format!("unknown tag <{name}>{}", did_you_mean(reference.suggest_tag(&name).into_iter().map(|tag| format!("<{tag}>")))));
+
+            return;
+        };
+
+        if let Some(suggestion) = tag.alternatives.suggestion() {
+            self.report(Rule::ObsoleteTag, DiagnosticTarget::Tag, format!("<{name}> is obsolete, {suggestion}"));
+        }
+
+        for (idx, attribute) in attributes.iter().enumerate() {
+            self.validate_attribute(namespace, tag, idx, *attribute);
+        }
+
+        self.validate_aria(tag, attributes);
+    }
+
+    // the explicit role must be allowed on the tag, and the explicit or implicit role must support
+    // the tag's aria states and properties, tags without a known role are not checked
+    fn validate_aria(&mut self, tag: &Tag, attributes: &[(&str, Option<&str>)]) {
+        let reference = self.reference;
+
+        let explicit = attributes.iter().position(|(name, _)| name.eq_ignore_ascii_case(ROLE));
+
+        let role = explicit.map_or_else(
+            || reference.get_implicit_role(tag),
+            |idx| reference.get_explicit_role(attributes[idx].1.unwrap_or_default()),
+        );
+
+        let Some(role) = role else {
+            return;
+        };
+
+        if let Some(idx) = explicit.filter(|_| !reference.is_allowed_role(tag, &role.name)) {
+            self.report(Rule::DisallowedRole, DiagnosticTarget::AttributeValue(idx), format!("role {:?} is not allowed on <{}>", role.name, tag.name));
+        }
+
+        for (idx, (name, _)) in attributes.iter().enumerate() {
+            let name = name.to_ascii_lowercase();
+
+            if name.starts_with(ARIA_PREFIX) && reference.get_aria_attribute(&name).is_some() &&
+                !reference.is_supported_aria_attribute(role, &name) {
+                self.report(Rule::UnsupportedAriaAttribute, DiagnosticTarget::AttributeName(idx), format!("attribute {name:?} is not supported by role {:?} of <{}>", role.name, tag.name));
+            }
+        }
+    }
+}
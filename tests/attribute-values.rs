@@ -0,0 +1,79 @@
+use web_reference::prelude::*;
+
+#[test]
+fn valid_values() {
+    let specs = WebReference::embedded();
+
+    for (tag, attribute, value) in [
+        ("a", "target", "_blank"),
+        ("a", "target", "_SELF"),
+        ("img", "width", "120"),
+        ("img", "width", "120px"),
+        ("input", "type", "number"),
+        ("input", "autocomplete", "off"),
+        ("input", "required", ""),
+        ("input", "required", "required"),
+        ("link", "rel", "stylesheet preload"),
+        ("html", "lang", "en-GB"),
+        ("div", "data-id", "1"),
+        ("div", "id", "main"),
+    ] {
+        assert_eq!(specs.validate_attribute_value(tag, attribute, value), Ok(()), "<{tag} {attribute}={value:?}>");
+    }
+}
+
+#[test]
+fn invalid_values() {
+    let specs = WebReference::embedded();
+
+    assert!(matches!(
+        specs.validate_attribute_value("a", "target", "_blnk"),
+        Err(ValueError::InvalidKeyword { expected, .. }) if expected == ["_blank", "_parent", "_self", "_top"]
+    ));
+    assert!(matches!(
+        specs.validate_attribute_value("img", "width", "wide"),
+        Err(ValueError::InvalidSyntax { expected: "a non negative number of pixels", .. })
+    ));
+    assert!(matches!(
+        specs.validate_attribute_value("input", "autocomplete", "maybe"),
+        Err(ValueError::InvalidSyntax { expected: "on or off", .. })
+    ));
+    assert!(matches!(
+        specs.validate_attribute_value("input", "type", "5"),
+        Err(ValueError::InvalidKeyword { .. })
+    ));
+    assert!(matches!(
+        specs.validate_attribute_value("div", "id", "two words"),
+        Err(ValueError::InvalidSyntax { .. })
+    ));
+}
+
+#[test]
+fn invalid_language_codes() {
+    let specs = WebReference::embedded();
+
+    assert!(matches!(specs.validate_attribute_value("html", "lang", "xx"), Err(ValueError::UnknownLanguage { .. })));
+    assert!(matches!(specs.validate_attribute_value("html", "lang", "en-XX"), Err(ValueError::UnknownCountry { .. })));
+}
+
+#[test]
+fn unknown_tags_and_attributes() {
+    let specs = WebReference::embedded();
+
+    assert_eq!(
+        specs.validate_attribute_value("blink", "id", "main"),
+        Err(ValueError::UnknownTag { tag: "blink".to_string() })
+    );
+    assert_eq!(
+        specs.validate_attribute_value("img", "herf", "index.html"),
+        Err(ValueError::UnknownAttribute { tag: "img".to_string(), attribute: "herf".to_string() })
+    );
+}
+
+#[test]
+fn keywords_of_enumerated_values() {
+    let specs = WebReference::embedded();
+    let autocomplete = specs.get_attributes("autocomplete").and_then(|tags| tags.get("input")).expect("expect <input autocomplete>");
+
+    assert_eq!(autocomplete.values.keywords("autocomplete"), Some(vec!["on", "off"]));
+}
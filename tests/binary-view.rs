@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use web_reference::prelude::*;
+
+#[test]
+fn round_trip() {
+    let specs = WebReference::embedded();
+    let binary = specs.to_binary().expect("expect the reference to archive");
+    let view = ReferenceView::new(&binary).expect("expect a valid binary");
+
+    assert_eq!(&view.to_reference().expect("expect the reference to deserialize"), specs);
+    assert_eq!(&WebReference::from_binary(&binary).expect("expect a valid binary"), specs);
+}
+
+#[test]
+fn lookups_borrow_from_the_binary() {
+    let specs = WebReference::embedded();
+    let binary = specs.to_binary().expect("expect the reference to archive");
+    let view = ReferenceView::new(&binary).expect("expect a valid binary");
+    let bytes = binary.as_ptr_range();
+    let borrowed = |range: Range<*const u8>| bytes.start <= range.start && range.end <= bytes.end;
+
+    let div = view.tag("div").expect("expect <div>");
+    let href = view.attribute("href", "a").expect("expect <a href>");
+    let click = view.event("onclick").expect("expect onclick");
+
+    assert_eq!(div.name.as_str(), "div");
+    assert_eq!(href.name.as_str(), "href");
+    assert_eq!(click.name.as_str(), "onclick");
+
+    assert!(borrowed(div.name.as_str().as_bytes().as_ptr_range()));
+    assert!(borrowed(href.name.as_str().as_bytes().as_ptr_range()));
+    assert!(borrowed(click.name.as_str().as_bytes().as_ptr_range()));
+
+    assert!(view.tag("blink").is_none());
+    assert!(view.attribute("href", "div").is_none());
+    assert!(view.event("click").is_none());
+}
+
+#[test]
+fn names_of_the_sections() {
+    let specs = WebReference::embedded();
+    let binary = specs.to_binary().expect("expect the reference to archive");
+    let view = ReferenceView::new(&binary).expect("expect a valid binary");
+
+    assert_eq!(sorted(view.tag_names()), sorted(specs.tags.keys().map(String::as_str)));
+    assert_eq!(sorted(view.attribute_names()), sorted(specs.attributes.keys().map(String::as_str)));
+    assert_eq!(sorted(view.event_names()), sorted(specs.events.keys().map(String::as_str)));
+}
+
+#[test]
+fn store_lookups_match_the_reference() {
+    let specs = WebReference::embedded();
+    let binary = specs.to_binary().expect("expect the reference to archive");
+    let view = ReferenceView::new(&binary).expect("expect a valid binary");
+
+    for tag in ["a", "div", "img", "blink"] {
+        assert_eq!(view.get_tag(tag), Ok(ReferenceStore::get_tag(specs, tag).unwrap_or_default()), "<{tag}>");
+        assert_eq!(
+            view.get_tag_attributes(tag).map(|attributes| by_name(attributes, |attribute| &attribute.name)),
+            Ok(by_name(ReferenceStore::get_tag_attributes(specs, tag).unwrap_or_default(), |attribute| &attribute.name)),
+            "<{tag}>"
+        );
+    }
+
+    for event in ["onclick", "click", "onblink"] {
+        assert_eq!(view.is_valid_event(event), Ok(specs.is_valid_event(event)), "{event}");
+        assert_eq!(view.get_event(event), Ok(ReferenceStore::get_event(specs, event).unwrap_or_default()), "{event}");
+    }
+
+    for category in [EventCategory::MouseEvents, EventCategory::KeyboardEvents] {
+        assert_eq!(
+            view.get_events_of_category(category).map(|events| by_name(events, |event| &event.name)),
+            Ok(by_name(ReferenceStore::get_events_of_category(specs, category).unwrap_or_default(), |event| &event.name))
+        );
+    }
+
+    for category in [TagCategory::Basic, TagCategory::FormsInput] {
+        assert_eq!(
+            view.get_tags_of_category(category).map(|tags| by_name(tags, |tag| &tag.name)),
+            Ok(by_name(ReferenceStore::get_tags_of_category(specs, category).unwrap_or_default(), |tag| &tag.name))
+        );
+    }
+}
+
+#[test]
+fn rejects_other_bytes() {
+    assert!(matches!(ReferenceView::new(br#"{"tags": {}}"#), Err(BinaryError::InvalidData { .. })));
+
+    let binary = WebReference::embedded().to_binary().expect("expect the reference to archive");
+
+    assert!(matches!(ReferenceView::new(&binary[..binary.len() / 2]), Err(BinaryError::InvalidData { .. })));
+}
+
+#[test]
+fn rejects_other_format_versions() {
+    let previous = WebReference { format_version: 0, ..WebReference::embedded().clone() };
+    let binary = previous.to_binary().expect("expect the reference to archive");
+
+    assert!(matches!(ReferenceView::new(&binary), Err(BinaryError::UnsupportedVersion { version: 0 })));
+}
+
+fn sorted<'a>(names: impl Iterator<Item=&'a str>) -> Vec<&'a str> {
+    let mut names = names.collect::<Vec<_>>();
+
+    names.sort_unstable();
+    names
+}
+
+// entries of sets are in the order of their hashes, which differ between a set and its archive
+fn by_name<T: Clone>(entries: Vec<Cow<'_, T>>, name: fn(&T) -> &String) -> Vec<T> {
+    let mut entries = entries.into_iter().map(Cow::into_owned).collect::<Vec<_>>();
+
+    entries.sort_by(|lhs, rhs| name(lhs).cmp(name(rhs)));
+    entries
+}
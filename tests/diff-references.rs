@@ -0,0 +1,60 @@
+use web_reference::prelude::*;
+
+#[test]
+fn identical_references_have_no_changes() {
+    let specs = WebReference::embedded();
+    let changelog = specs.diff(specs);
+
+    assert!(changelog.is_empty());
+    assert_eq!(changelog.to_markdown(), "# Changelog\n\nNo changes\n");
+}
+
+#[test]
+fn added_removed_and_changed_entities() {
+    let previous = WebReference::embedded();
+    let mut newer = previous.clone();
+
+    let mut search = newer.tags["div"].clone();
+
+    search.name = "search".to_string();
+    newer.tags.insert(search.name.clone(), search);
+    newer.tags.retain(|name, _| name != "center");
+
+    let div = newer.tags.get_mut("div").expect("expect <div>");
+
+    div.description = Description::from("a generic container");
+    div.supported.supported.insert(Browser::Chrome, Support::No);
+
+    if let AttributeValue::Values(values) = &mut newer.attributes.get_mut("target").and_then(|tags| tags.get_mut("a")).expect("expect <a target>").values {
+        values.insert("_unfencedTop".to_string(), Value { name: "_unfencedTop".to_string(), ..Value::default() });
+    }
+
+    let changelog = previous.diff(&newer);
+
+    assert_eq!(changelog.tags.added, vec![EntityName { name: "search".to_string(), tag: None }]);
+    assert_eq!(changelog.tags.removed, vec![EntityName { name: "center".to_string(), tag: None }]);
+
+    let [div] = changelog.tags.changed.as_slice() else {
+        panic!("expect only <div> to change, not {:?}", changelog.tags.changed);
+    };
+
+    assert_eq!(div.entity.name, "div");
+    assert_eq!(div.description.as_ref().map(|change| change.after.as_ref()), Some("a generic container"));
+    assert_eq!(div.support.iter().map(|change| (change.browser, change.after.clone())).collect::<Vec<_>>(), vec![(Browser::Chrome, Some(Support::No))]);
+
+    let [target] = changelog.attributes.changed.as_slice() else {
+        panic!("expect only <a target> to change, not {:?}", changelog.attributes.changed);
+    };
+
+    assert_eq!(target.entity, EntityName { name: "target".to_string(), tag: Some("a".to_string()) });
+    assert_eq!(target.added_values, vec!["_unfencedTop".to_string()]);
+    assert!(target.removed_values.is_empty());
+
+    assert!(changelog.events.is_empty());
+
+    let markdown = changelog.to_markdown();
+
+    assert!(markdown.contains("\n## Tags\n"));
+    assert!(markdown.contains("\n## Attributes\n"));
+    assert!(!markdown.contains("\n## Events\n"));
+}
@@ -0,0 +1,49 @@
+use web_reference::prelude::*;
+
+// the html-5-specs.json that predates format versions
+const SPECS_V0: &str = include_str!("fixtures/html-5-specs-v0.json");
+
+fn version(major: u32, minor: u32, prefix: Option<VendorPrefix>, unprefixed: Option<BrowserVersion>, caveat: usize) -> Support {
+    Support::Version { version: BrowserVersion::new(major, minor), prefix, unprefixed, caveat }
+}
+
+#[test]
+fn migrates_version_0() {
+    let specs = WebReference::load_from_str(SPECS_V0).expect("expect version 0 to migrate");
+
+    assert_eq!(specs.format_version, FORMAT_VERSION);
+    assert_eq!((specs.tags.len(), specs.attributes.len(), specs.events.len()), (122, 111, 85));
+}
+
+#[test]
+fn migrates_raw_browser_versions() {
+    let specs = WebReference::load_from_str(SPECS_V0).expect("expect version 0 to migrate");
+
+    let tag = |tag: &str, browser| specs.tags[tag].supported.supported[&browser].clone();
+    let event = |event: &str, browser| specs.events[event].supported.supported[&browser].clone();
+
+    assert_eq!(tag("article", Browser::Chrome), version(6, 0, None, None, 0));
+    assert_eq!(tag("bdi", Browser::Safari), Support::No);
+    assert_eq!(tag("dialog", Browser::Firefox), version(53, 0, None, None, 1));
+    assert_eq!(tag("output", Browser::Edge), version(13, 0, None, None, 0));
+    assert_eq!(event("onanimationend", Browser::Chrome), version(4, 0, Some(VendorPrefix::Webkit), None, 0));
+    assert_eq!(event("onanimationend", Browser::Opera), version(15, 0, Some(VendorPrefix::Webkit), Some(BrowserVersion::new(12, 1)), 0));
+    // version 0 scraped the header of the support table of `onclick` as its versions
+    assert_eq!(event("onclick", Browser::Chrome), Support::Unknown);
+}
+
+#[test]
+fn migrates_css_alternatives() {
+    let specs = WebReference::load_from_str(SPECS_V0).expect("expect version 0 to migrate");
+
+    for tag in ["basefont", "big", "center", "font", "tt"] {
+        assert_eq!(specs.tags[tag].alternatives, TagAlternatives::Css(AlternativeCssProperties::default()), "<{tag}>");
+    }
+}
+
+#[test]
+fn rejects_newer_versions() {
+    let newer = format!(r#"{{"format-version": {}}}"#, FORMAT_VERSION + 1);
+
+    assert!(WebReference::load_from_str(&newer).is_err());
+}
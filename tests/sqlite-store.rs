@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use web_reference::prelude::*;
+
+#[test]
+fn tags_match_the_reference() {
+    let specs = WebReference::embedded();
+    let store = SqliteStore::in_memory(specs).expect("expect the reference to export");
+
+    for (name, tag) in &specs.tags {
+        assert_eq!(store.get_tag(name).expect("expect lookup to succeed"), Some(Cow::Borrowed(tag)), "<{name}>");
+        assert_eq!(store.is_valid_tag(name), Ok(true), "<{name}>");
+    }
+
+    assert_eq!(store.get_tag("blink").expect("expect lookup to succeed"), None);
+    assert_eq!(store.is_valid_tag("blink"), Ok(false));
+}
+
+#[test]
+fn attributes_match_the_reference() {
+    let specs = WebReference::embedded();
+    let store = SqliteStore::in_memory(specs).expect("expect the reference to export");
+
+    for (name, tags) in &specs.attributes {
+        for (tag, attribute) in tags {
+            assert_eq!(store.get_attribute(name, tag).expect("expect lookup to succeed"), Some(Cow::Borrowed(attribute)), "<{tag} {name}>");
+        }
+
+        assert_eq!(store.is_valid_attribute(name), Ok(true), "{name}");
+    }
+
+    assert_eq!(store.get_attribute("href", "div").expect("expect lookup to succeed"), None);
+    assert_eq!(store.is_valid_attribute("herf"), Ok(false));
+}
+
+#[test]
+fn events_match_the_reference() {
+    let specs = WebReference::embedded();
+    let store = SqliteStore::in_memory(specs).expect("expect the reference to export");
+
+    for (name, event) in &specs.events {
+        assert_eq!(store.get_event(name).expect("expect lookup to succeed"), Some(Cow::Borrowed(event)), "{name}");
+    }
+
+    for event in ["onclick", "click", "onclik"] {
+        assert_eq!(store.is_valid_event(event), Ok(specs.is_valid_event(event)), "{event}");
+    }
+}
+
+#[test]
+fn lists_match_the_reference() {
+    let specs = WebReference::embedded();
+    let store = SqliteStore::in_memory(specs).expect("expect the reference to export");
+
+    for tag in specs.tags.keys() {
+        assert_eq!(
+            by_name(store.get_tag_attributes(tag).expect("expect lookup to succeed"), |attribute| &attribute.name),
+            by_name(ReferenceStore::get_tag_attributes(specs, tag).unwrap_or_default(), |attribute| &attribute.name),
+            "<{tag}>"
+        );
+    }
+
+    for category in specs.events_categorized.keys() {
+        assert_eq!(
+            by_name(store.get_events_of_category(*category).expect("expect lookup to succeed"), |event| &event.name),
+            by_name(ReferenceStore::get_events_of_category(specs, *category).unwrap_or_default(), |event| &event.name),
+            "{category:?}"
+        );
+    }
+
+    for category in specs.tags_categorized.keys() {
+        assert_eq!(
+            by_name(store.get_tags_of_category(*category).expect("expect lookup to succeed"), |tag| &tag.name),
+            by_name(ReferenceStore::get_tags_of_category(specs, *category).unwrap_or_default(), |tag| &tag.name),
+            "{category:?}"
+        );
+    }
+}
+
+// the store lists entries in the order of its rows, the reference in the order of its sets
+fn by_name<T: Clone>(entries: Vec<Cow<'_, T>>, name: fn(&T) -> &String) -> Vec<T> {
+    let mut entries = entries.into_iter().map(Cow::into_owned).collect::<Vec<_>>();
+
+    entries.sort_by(|lhs, rhs| name(lhs).cmp(name(rhs)));
+    entries
+}
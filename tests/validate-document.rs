@@ -0,0 +1,74 @@
+use web_reference::prelude::*;
+
+const DOCUMENT: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<body>
+  <center>obsolete</center>
+  <a herf="index.html" onclik="go()">home</a>
+  <img src="logo.png" width="wide" checked>
+  <a href="help.html" target="_blnk">help</a>
+  <input type="text" autocomplete="maybe" required>
+  <blink>unknown</blink>
+</body>
+</html>
+"#;
+
+const VALID_DOCUMENT: &str = r#"<!DOCTYPE html>
+<html lang="en-GB">
+<head>
+  <title>valid</title>
+</head>
+<body>
+  <a href="index.html" target="_blank" onclick="go()">home</a>
+  <img src="logo.png" alt="logo" width="120">
+  <input type="text" autocomplete="off" required>
+  <div data-id="1" hidden>text</div>
+</body>
+</html>
+"#;
+
+#[test]
+fn diagnostics_of_a_document() {
+    let diagnostics = WebReference::embedded().validate_document(DOCUMENT);
+
+    let found = diagnostics.iter()
+        .map(|diagnostic| (diagnostic.span.start.line, diagnostic.span.start.column, diagnostic.rule))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        found,
+        vec![
+            (4, 3, Rule::ObsoleteTag),
+            (5, 6, Rule::UnknownAttribute),
+            (5, 24, Rule::UnknownEvent),
+            (6, 30, Rule::InvalidAttributeValue),
+            (6, 36, Rule::DisallowedAttribute),
+            (7, 31, Rule::InvalidAttributeValue),
+            (8, 36, Rule::InvalidAttributeValue),
+            (9, 3, Rule::UnknownTag),
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_suggest_the_closest_names() {
+    let messages = WebReference::embedded().validate_document(DOCUMENT).into_iter()
+        .map(|diagnostic| diagnostic.to_string())
+        .collect::<Vec<_>>();
+
+    assert!(messages.contains(&r#"5:6: error[unknown-attribute]: unknown attribute "herf" on <a>, did you mean "href"?"#.to_string()));
+    assert!(messages.contains(&r#"5:24: error[unknown-event]: unknown event handler attribute "onclik", did you mean "onclick"?"#.to_string()));
+    assert!(messages.contains(&"9:3: error[unknown-tag]: unknown tag <blink>, did you mean <link>?".to_string()));
+}
+
+#[test]
+fn severity_follows_the_rule() {
+    for diagnostic in WebReference::embedded().validate_document(DOCUMENT) {
+        assert_eq!(diagnostic.severity, diagnostic.rule.severity());
+    }
+}
+
+#[test]
+fn valid_document_has_no_diagnostics() {
+    assert_eq!(WebReference::embedded().validate_document(VALID_DOCUMENT), Diagnostics::new());
+}